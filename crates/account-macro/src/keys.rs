@@ -1,107 +1,188 @@
 use {
     proc_macro2::TokenStream,
     quote::{format_ident, quote},
-    syn::{spanned::Spanned, Fields, Ident, Meta, Type},
+    syn::{spanned::Spanned, Attribute, Expr, Fields, Ident, LitInt, Meta, Type},
 };
 
+/// Constant seeds configured with `#[seeds(..)]` on the account struct.
+#[derive(Default)]
+pub struct SeedsAttr {
+    /// Whether the attribute is present.
+    pub is_set: bool,
+    /// Replaces the default lowercased struct name prefix.
+    pub prefix: Option<Expr>,
+    /// Removes the prefix entirely.
+    pub no_prefix: bool,
+    /// Constant seeds placed after the prefix and before the keys.
+    pub constants: Vec<Expr>,
+}
+
+impl SeedsAttr {
+    /// Constant seed expressions in order, starting with the prefix.
+    fn constant_seeds(&self, account_name: &Ident) -> Vec<TokenStream> {
+        let prefix = match (&self.prefix, self.no_prefix) {
+            (Some(prefix), _) => Some(quote!(#prefix)),
+            (None, true) => None,
+            (None, false) => {
+                let lowercase_name = account_name.to_string().to_lowercase();
+                Some(quote!(#lowercase_name.as_bytes()))
+            }
+        };
+
+        prefix
+            .into_iter()
+            .chain(self.constants.iter().map(|c| quote!(#c)))
+            .collect()
+    }
+}
+
+impl TryFrom<&[Attribute]> for SeedsAttr {
+    type Error = syn::Error;
+
+    fn try_from(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut seeds = SeedsAttr::default();
+        let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("seeds")) else {
+            return Ok(seeds);
+        };
+
+        seeds.is_set = true;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                seeds.prefix = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("no_prefix") {
+                seeds.no_prefix = true;
+            } else if meta.path.is_ident("constant") {
+                seeds.constants.push(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error(
+                    "Unknown `seeds` argument, expected `prefix`, `no_prefix` or `constant`",
+                ));
+            }
+            Ok(())
+        })?;
+
+        if seeds.no_prefix && seeds.prefix.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`prefix` and `no_prefix` cannot be used together",
+            ));
+        }
+
+        Ok(seeds)
+    }
+}
+
 pub struct PrimaryKey {
     pub name: Ident,
     pub ty: Type,
+    pub order: Option<u32>,
 }
 
 impl PrimaryKey {
-    fn type_ident(&self) -> Option<&Ident> {
-        match &self.ty {
-            Type::Path(path) => path.path.get_ident(),
-            _ => None,
-        }
-    }
-
     /// Type for the field in the seeds holder struct.
-    fn seeds_field_ty(&self) -> Result<TokenStream, TokenStream> {
-        let Some(ident) = self.type_ident() else {
-            return Err(
-                syn::Error::new(self.name.span(), "This type cannot be used as a key")
-                    .to_compile_error(),
-            );
-        };
-        match ident.to_string().as_str() {
-            "Address" => Ok(quote! { &'a [u8] }),
-            "u64" => Ok(quote! { [u8; 8] }),
-            "u32" => Ok(quote! { [u8; 4] }),
-            "u16" => Ok(quote! { [u8; 2] }),
-            "u8" => Ok(quote! { [u8; 1] }),
-            _ => Err(
-                syn::Error::new(self.name.span(), "This type cannot be used as a key")
-                    .to_compile_error(),
-            ),
-        }
+    fn seeds_field_ty(&self) -> TokenStream {
+        let ty = &self.ty;
+        quote! { <#ty as SeedKey>::Bytes<'a> }
     }
 
     /// Expression to construct the seeds field from `self.field`.
     fn self_init_expr(&self) -> TokenStream {
         let name = &self.name;
-        match self.type_ident().map(|i| i.to_string()) {
-            Some(ref s) if s == "Address" => quote! { self.#name.as_ref() },
-            _ => quote! { self.#name.to_le_bytes() },
-        }
+        quote! { SeedKey::seed_bytes(&self.#name) }
     }
 
     /// Expression to construct the seeds field from a derive parameter.
     fn derive_init_expr(&self) -> TokenStream {
         let name = &self.name;
-        match self.type_ident().map(|i| i.to_string()) {
-            Some(ref s) if s == "Address" => quote! { #name.as_ref() },
-            _ => quote! { #name.to_le_bytes() },
-        }
+        quote! { SeedKey::seed_bytes(#name) }
     }
 
     /// Expression to get `&[u8]` from the seeds holder struct field.
     fn seed_ref_expr(&self) -> TokenStream {
         let name = &self.name;
-        match self.type_ident().map(|i| i.to_string()) {
-            Some(ref s) if s == "Address" => quote! { self.#name },
-            _ => quote! { self.#name.as_ref() },
-        }
+        quote! { self.#name.as_ref() }
     }
 }
 
 pub struct PrimaryKeys(Vec<PrimaryKey>);
 
 impl PrimaryKeys {
-    pub fn split_for_impl(&self, account_name: &Ident) -> TokenStream {
-        let has_keys = !self.0.is_empty();
-        let n_seeds = self.0.len() + 1;
-        let n_seeds_with_bump = n_seeds + 1;
+    pub fn split_for_impl(
+        &self,
+        account_name: &Ident,
+        seeds_attr: &SeedsAttr,
+        bump: Option<&Ident>,
+    ) -> Result<TokenStream, syn::Error> {
+        if self.0.is_empty() && !seeds_attr.is_set {
+            if let Some(bump) = bump {
+                return Err(syn::Error::new(
+                    bump.span(),
+                    "`#[bump]` requires `#[key]` fields or a `#[seeds(..)]` attribute",
+                ));
+            }
+            return Ok(quote!());
+        }
 
-        if !has_keys {
-            return quote!();
+        let constant_seeds = seeds_attr.constant_seeds(account_name);
+        if constant_seeds.is_empty() && self.0.is_empty() {
+            return Err(syn::Error::new(
+                account_name.span(),
+                "An account without `#[key]` fields needs at least one constant seed",
+            ));
         }
 
+        let n_constants = constant_seeds.len();
+        let n_seeds = n_constants + self.0.len();
+        let n_seeds_with_bump = n_seeds + 1;
+
         let seeds_struct_name = format_ident!("{}Seeds", account_name);
 
-        let struct_fields = self.0.iter().map(|k| {
-            let name = &k.name;
-            let ty = match k.seeds_field_ty() {
-                Ok(ty) => ty,
-                Err(err) => return err,
-            };
-            quote! { #name: #ty }
-        });
+        let mut struct_fields: Vec<_> = self
+            .0
+            .iter()
+            .map(|k| {
+                let name = &k.name;
+                let ty = k.seeds_field_ty();
+                quote! { #name: #ty }
+            })
+            .collect();
 
-        let self_init_fields = self.0.iter().map(|k| {
-            let name = &k.name;
-            let expr = k.self_init_expr();
-            quote! { #name: #expr }
-        });
+        let mut self_init_fields: Vec<_> = self
+            .0
+            .iter()
+            .map(|k| {
+                let name = &k.name;
+                let expr = k.self_init_expr();
+                quote! { #name: #expr }
+            })
+            .collect();
 
-        let derive_init_fields = self.0.iter().map(|k| {
-            let name = &k.name;
-            let expr = k.derive_init_expr();
-            quote! { #name: #expr }
-        });
+        let mut derive_init_fields: Vec<_> = self
+            .0
+            .iter()
+            .map(|k| {
+                let name = &k.name;
+                let expr = k.derive_init_expr();
+                quote! { #name: #expr }
+            })
+            .collect();
+
+        // Keeps the `'a` lifetime used when there is no key to borrow.
+        if self.0.is_empty() {
+            struct_fields.push(quote! { _marker: core::marker::PhantomData<&'a ()> });
+            self_init_fields.push(quote! { _marker: core::marker::PhantomData });
+            derive_init_fields.push(quote! { _marker: core::marker::PhantomData });
+        }
 
-        let seed_refs: Vec<_> = self.0.iter().map(|k| k.seed_ref_expr()).collect();
+        let seed_refs: Vec<_> = (0..n_constants)
+            .map(|i| quote! { #account_name::CONSTANT_SEEDS[#i] })
+            .chain(self.0.iter().map(|k| k.seed_ref_expr()))
+            .collect();
+
+        let key_len_checks = self.0.iter().map(|k| {
+            let ty = &k.ty;
+            quote! { const _: () = <#ty as SeedKey>::ASSERT_MAX_LEN; }
+        });
 
         let parameters_with_lifetime = self.0.iter().map(|k| {
             let name = &k.name;
@@ -110,29 +191,60 @@ impl PrimaryKeys {
         });
         let parameters_list_with_lifetime = quote! { #(#parameters_with_lifetime),* };
 
-        let lowercase_name = account_name.to_string().to_lowercase();
+        let stored_bump = match bump {
+            Some(bump) => quote! {
+                const HAS_STORED_BUMP: bool = true;
+
+                #[inline(always)]
+                fn stored_bump(&self) -> Option<u8> {
+                    Some(self.#bump)
+                }
+
+                #[inline(always)]
+                fn write_stored_bump(data: &mut [u8], bump: u8) {
+                    data[<Self as Discriminator>::DISCRIMINATOR.len() + core::mem::offset_of!(Self, #bump)] = bump;
+                }
+            },
+            None => quote! {
+                const HAS_STORED_BUMP: bool = false;
+
+                #[inline(always)]
+                fn stored_bump(&self) -> Option<u8> {
+                    None
+                }
+
+                #[inline(always)]
+                fn write_stored_bump(_data: &mut [u8], _bump: u8) {}
+            },
+        };
+
+        Ok(quote! {
+            #(#key_len_checks)*
 
-        quote! {
             pub struct #seeds_struct_name<'a> {
                 #(#struct_fields),*
             }
 
             impl<'a> #seeds_struct_name<'a> {
                 pub fn as_seeds(&'a self) -> [&'a [u8]; #n_seeds] {
-                    [#account_name::BASE_SEED, #(#seed_refs),*]
+                    [#(#seed_refs),*]
                 }
 
                 pub fn seeds_with_bump(&'a self, bump: &'a [u8]) -> [&'a [u8]; #n_seeds_with_bump] {
-                    [#account_name::BASE_SEED, #(#seed_refs),*, bump]
+                    [#(#seed_refs,)* bump]
                 }
 
                 pub fn signer_seeds_with_bump(&'a self, bump: &'a [u8]) -> [Seed<'a>; #n_seeds_with_bump] {
-                    seeds!(#account_name::BASE_SEED, #(#seed_refs),*, bump)
+                    seeds!(#(#seed_refs,)* bump)
                 }
             }
 
+            impl StoredBump for #account_name {
+                #stored_bump
+            }
+
             impl #account_name {
-                const BASE_SEED: &'static [u8] = #lowercase_name.as_bytes();
+                const CONSTANT_SEEDS: [&'static [u8]; #n_constants] = [#(#constant_seeds),*];
 
                 pub fn seeds(&self) -> #seeds_struct_name<'_> {
                     #seeds_struct_name {
@@ -146,7 +258,7 @@ impl PrimaryKeys {
                     }
                 }
             }
-        }
+        })
     }
 }
 
@@ -154,35 +266,96 @@ impl TryFrom<&Fields> for PrimaryKeys {
     type Error = syn::Error;
 
     fn try_from(value: &Fields) -> Result<Self, syn::Error> {
-        match value {
-            Fields::Named(fields) => {
-                let mut primary_keys = Vec::new();
-
-                for field in fields.named.iter() {
-                    let has_key = field.attrs.iter().any(|attr| {
-                        if let Meta::Path(path) = &attr.meta {
-                            path.get_ident().is_some_and(|ident| *ident == "key")
-                        } else {
-                            false
-                        }
-                    });
-
-                    if has_key {
-                        if let Some(ident) = &field.ident {
-                            primary_keys.push(PrimaryKey {
-                                name: ident.clone(),
-                                ty: field.ty.clone(),
-                            });
-                        }
-                    }
-                }
-
-                Ok(PrimaryKeys(primary_keys))
-            }
-            _ => Err(syn::Error::new(
+        let Fields::Named(fields) = value else {
+            return Err(syn::Error::new(
                 value.span(),
                 "Only named fields are currently handled",
-            )),
+            ));
+        };
+
+        let mut primary_keys = Vec::new();
+        for field in fields.named.iter() {
+            let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("key")) else {
+                continue;
+            };
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+
+            primary_keys.push(PrimaryKey {
+                name: ident.clone(),
+                ty: field.ty.clone(),
+                order: parse_order(attr)?,
+            });
+        }
+
+        let ordered = primary_keys.iter().filter(|k| k.order.is_some()).count();
+        if ordered != 0 && ordered != primary_keys.len() {
+            return Err(syn::Error::new(
+                value.span(),
+                "`order` must be set on every `#[key]` field or none of them",
+            ));
+        }
+
+        primary_keys.sort_by_key(|k| k.order);
+        if let Some(keys) = primary_keys
+            .windows(2)
+            .find(|keys| keys[0].order.is_some() && keys[0].order == keys[1].order)
+        {
+            return Err(syn::Error::new(
+                keys[1].name.span(),
+                "Duplicate `order` for `#[key]` fields",
+            ));
+        }
+
+        Ok(PrimaryKeys(primary_keys))
+    }
+}
+
+/// Returns the `#[bump]` field, which must be a `u8`.
+pub fn parse_bump_field(value: &Fields) -> Result<Option<Ident>, syn::Error> {
+    let mut bump = None;
+    for field in value.iter() {
+        let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("bump")) else {
+            continue;
+        };
+        if bump.is_some() {
+            return Err(syn::Error::new(
+                attr.span(),
+                "Only one field can be marked with `#[bump]`",
+            ));
+        }
+        if !matches!(&field.ty, Type::Path(ty) if ty.path.is_ident("u8")) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "The `#[bump]` field must be a `u8`",
+            ));
+        }
+        bump = field.ident.clone();
+    }
+    Ok(bump)
+}
+
+/// Parses the optional `order = n` argument of a `#[key]` attribute.
+fn parse_order(attr: &Attribute) -> Result<Option<u32>, syn::Error> {
+    match &attr.meta {
+        Meta::Path(_) => Ok(None),
+        Meta::List(_) => {
+            let mut order = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    let value: LitInt = meta.value()?.parse()?;
+                    order = Some(value.base10_parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown `key` argument, expected `order`"))
+                }
+            })?;
+            Ok(order)
         }
+        Meta::NameValue(_) => Err(syn::Error::new(
+            attr.span(),
+            "Expected `#[key]` or `#[key(order = n)]`",
+        )),
     }
 }
@@ -1,11 +1,17 @@
 use {
-    keys::PrimaryKeys,
+    keys::{parse_bump_field, PrimaryKeys, SeedsAttr},
+    migrate::MigrateFromAttr,
+    owner::OwnerAttr,
     quote::{quote, ToTokens},
+    space::{fields_max_size, reject_max_len},
     syn::{parse_macro_input, punctuated::Punctuated, spanned::Spanned, Error, Item, Path, Token},
-    typhoon_discriminator::DiscriminatorBuilder,
+    typhoon_syn::AccountDiscriminator,
 };
 
 mod keys;
+mod migrate;
+mod owner;
+mod space;
 
 fn has_derive(attrs: &[syn::Attribute], derive_name: &str) -> bool {
     attrs
@@ -23,7 +29,20 @@ fn has_derive(attrs: &[syn::Attribute], derive_name: &str) -> bool {
         })
 }
 
-#[proc_macro_derive(AccountState, attributes(key, no_space))]
+#[proc_macro_derive(
+    AccountState,
+    attributes(
+        key,
+        bump,
+        discriminator,
+        max_len,
+        migrate_from,
+        no_space,
+        owner,
+        seeds,
+        trailing
+    )
+)]
 pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let item = parse_macro_input!(item as Item);
     let (attrs, name, generics, fields) = match item {
@@ -40,23 +59,112 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     };
 
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    let keys = match PrimaryKeys::try_from(fields) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let seeds_attr = match SeedsAttr::try_from(attrs.as_slice()) {
+        Ok(seeds_attr) => seeds_attr,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let bump = match parse_bump_field(fields) {
+        Ok(bump) => bump,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if let Some(bump) = &bump {
+        if has_derive(attrs, "SchemaRead") || has_derive(attrs, "BorshDeserialize") {
+            return Error::new(
+                bump.span(),
+                "`#[bump]` is only supported on bytemuck accounts",
+            )
+            .into_compile_error()
+            .into();
+        }
+    }
+    let seeded_trait = match keys.split_for_impl(name, &seeds_attr, bump.as_ref()) {
+        Ok(seeded_trait) => seeded_trait,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let discriminator = match AccountDiscriminator::try_from(attrs.as_slice()) {
+        Ok(discriminator) => discriminator.build(&name.to_string()),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let owned_by = match OwnerAttr::try_from(attrs.as_slice()) {
+        Ok(owner) => owner.owned_by(),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let migratable = match MigrateFromAttr::try_from(attrs.as_slice()) {
+        Ok(migrate_from) => migrate_from.to_impl(name),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let len_prefix = if has_derive(attrs, "SchemaRead") {
+        // Bincode lengths are `u64`.
+        Some(8usize)
+    } else if has_derive(attrs, "BorshDeserialize") {
+        Some(4)
+    } else {
+        None
+    };
     let space_token = if attrs.iter().any(|a| a.path().is_ident("no_space")) {
         None
+    } else if let Some(len_prefix) = len_prefix {
+        let max_size = match fields_max_size(fields, len_prefix) {
+            Ok(max_size) => max_size,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        Some(quote! {
+            impl MaxSize for #name {
+                const MAX_SIZE: usize = #max_size;
+            }
+
+            impl #name {
+                pub const SPACE: usize = <#name as Discriminator>::DISCRIMINATOR.len() + <#name as MaxSize>::MAX_SIZE;
+            }
+        })
     } else {
+        if let Err(err) = reject_max_len(fields) {
+            return err.to_compile_error().into();
+        }
         Some(quote! {
             impl #name {
                 pub const SPACE: usize = <#name as Discriminator>::DISCRIMINATOR.len() + core::mem::size_of::<#name>();
             }
         })
     };
-    let (_, ty_generics, where_clause) = generics.split_for_impl();
-
-    let keys = match PrimaryKeys::try_from(fields) {
-        Ok(fields) => fields,
-        Err(err) => return err.to_compile_error().into(),
+    let trailing = match attrs.iter().find(|a| a.path().is_ident("trailing")) {
+        Some(attr) if len_prefix.is_some() => {
+            return Error::new_spanned(attr, "`#[trailing]` is only supported on bytemuck accounts")
+                .into_compile_error()
+                .into()
+        }
+        Some(attr) => match attr.parse_args::<syn::Type>() {
+            Ok(entry) => Some(entry),
+            Err(err) => return err.to_compile_error().into(),
+        },
+        None => None,
     };
-    let seeded_trait = keys.split_for_impl(name);
-    let discriminator = DiscriminatorBuilder::new(&name.to_string()).build();
+    // Bytemuck bodies are read in place after the discriminator, which must keep them aligned.
+    // Account data is only guaranteed 8-byte alignment, which is also the largest one on SBF.
+    let alignment_check = len_prefix.is_none().then(|| {
+        let entry_align = trailing
+            .as_ref()
+            .map(|entry| quote!(core::mem::align_of::<#entry>()))
+            .unwrap_or_else(|| quote!(1));
+        quote! {
+            const _: () = {
+                let align = match (core::mem::align_of::<#name>(), #entry_align) {
+                    (header, entry) if header < entry => entry,
+                    (header, _) => header,
+                };
+                assert!(
+                    <#name as Discriminator>::DISCRIMINATOR.len().is_multiple_of(if align < 8 { align } else { 8 }),
+                    "the discriminator length of a bytemuck account must be a multiple of its alignment"
+                );
+            };
+        }
+    });
     let account_strategy = if has_derive(attrs, "SchemaRead") {
         quote!(
             WincodeStrategy<
@@ -69,7 +177,9 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
             >
         )
     } else if has_derive(attrs, "BorshDeserialize") {
-        quote!(BorshStrategy)
+        quote!(BorshStrategy<false>)
+    } else if let Some(entry) = trailing {
+        quote!(TrailingStrategy<#entry>)
     } else {
         quote!(BytemuckStrategy)
     };
@@ -78,7 +188,7 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
         impl CheckOwner for #name #ty_generics #where_clause {
             #[inline(always)]
             fn owned_by(owner: &Address) -> bool {
-                address_eq(owner, &crate::ID)
+                #owned_by
             }
         }
 
@@ -90,9 +200,13 @@ pub fn derive_account(item: proc_macro::TokenStream) -> proc_macro::TokenStream
             type Strategy = #account_strategy;
         }
 
+        #alignment_check
+
         #space_token
 
         #seeded_trait
+
+        #migratable
     }
     .into_token_stream()
     .into()
@@ -0,0 +1,73 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{punctuated::Punctuated, Attribute, Ident, Path, Token},
+};
+
+/// Previous layouts listed with `#[migrate_from(..)]` on the account struct.
+#[derive(Default)]
+pub struct MigrateFromAttr {
+    pub previous: Vec<Path>,
+}
+
+impl MigrateFromAttr {
+    /// Generates the `Migratable` implementation, nothing without the attribute.
+    pub fn to_impl(&self, account_name: &Ident) -> Option<TokenStream> {
+        if self.previous.is_empty() {
+            return None;
+        }
+
+        let previous = &self.previous;
+        Some(quote! {
+            impl Migratable for #account_name {
+                #[inline(always)]
+                fn is_known_layout(data: &[u8]) -> bool {
+                    data.starts_with(<Self as Discriminator>::DISCRIMINATOR)
+                        #(|| data.starts_with(<#previous as Discriminator>::DISCRIMINATOR))*
+                }
+
+                fn migrate(data: &[u8]) -> Result<Option<Self>, ProgramError> {
+                    if data.starts_with(<Self as Discriminator>::DISCRIMINATOR) {
+                        return Ok(None);
+                    }
+
+                    #(
+                        if data.starts_with(<#previous as Discriminator>::DISCRIMINATOR) {
+                            let previous = <<#previous as DataStrategy>::Strategy as Accessor<'_, #previous>>::access(
+                                &data[<#previous as Discriminator>::DISCRIMINATOR.len()..],
+                            )?;
+                            return Ok(Some(<Self as MigrateFrom<#previous>>::migrate_from(
+                                <_ as core::borrow::Borrow<#previous>>::borrow(&previous),
+                            )));
+                        }
+                    )*
+
+                    Err(ProgramError::InvalidAccountData)
+                }
+            }
+        })
+    }
+}
+
+impl TryFrom<&[Attribute]> for MigrateFromAttr {
+    type Error = syn::Error;
+
+    fn try_from(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut migrate_from = MigrateFromAttr::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("migrate_from"))
+        {
+            let previous = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+            if previous.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`migrate_from` expects at least one previous layout",
+                ));
+            }
+            migrate_from.previous.extend(previous);
+        }
+
+        Ok(migrate_from)
+    }
+}
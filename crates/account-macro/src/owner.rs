@@ -0,0 +1,59 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{Attribute, Expr},
+};
+
+/// Owners accepted by `CheckOwner`, set with `#[owner(..)]` on the account struct.
+pub enum OwnerAttr {
+    /// Owned by the current program, `crate::ID`.
+    Program,
+    /// Owned by one of the listed programs.
+    AnyOf(Vec<Expr>),
+}
+
+impl OwnerAttr {
+    /// Body of `CheckOwner::owned_by`, checking the `owner` variable.
+    pub fn owned_by(&self) -> TokenStream {
+        match self {
+            OwnerAttr::Program => quote!(address_eq(owner, &crate::ID)),
+            OwnerAttr::AnyOf(owners) => quote!(#(address_eq(owner, &#owners))||*),
+        }
+    }
+}
+
+impl TryFrom<&[Attribute]> for OwnerAttr {
+    type Error = syn::Error;
+
+    fn try_from(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("owner")) else {
+            return Ok(OwnerAttr::Program);
+        };
+
+        let owners = match attr.parse_args::<Expr>()? {
+            Expr::Assign(assign) if is_any_of(&assign.left) => match *assign.right {
+                Expr::Array(array) => array.elems.into_iter().collect(),
+                right => {
+                    return Err(syn::Error::new_spanned(
+                        right,
+                        "`any_of` expects an array of program IDs",
+                    ))
+                }
+            },
+            owner => vec![owner],
+        };
+
+        if owners.is_empty() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`any_of` expects at least one program ID",
+            ));
+        }
+
+        Ok(OwnerAttr::AnyOf(owners))
+    }
+}
+
+fn is_any_of(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident("any_of"))
+}
@@ -0,0 +1,116 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{
+        punctuated::Punctuated, Attribute, Expr, Fields, GenericArgument, PathArguments, Token,
+        Type,
+    },
+};
+
+/// Returns the `#[max_len(..)]` attribute of a field, if any.
+fn max_len_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident("max_len"))
+}
+
+/// Returns the first generic type argument of the last path segment, `T` in `Vec<T>`.
+fn inner_ty(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty_path) = ty else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(args) = &ty_path.path.segments.last()?.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// Maximum serialized size of `ty`, taking one `max_len` value for each `Vec` or `String`
+/// from the outermost to the innermost.
+fn ty_max_size<'a>(
+    ty: &Type,
+    lens: &mut impl Iterator<Item = &'a Expr>,
+    len_prefix: usize,
+) -> Result<TokenStream, syn::Error> {
+    if let Type::Array(array) = ty {
+        let len = &array.len;
+        let elem = ty_max_size(&array.elem, lens, len_prefix)?;
+        return Ok(quote!((#len) * (#elem)));
+    }
+
+    let ident = match ty {
+        Type::Path(ty_path) => ty_path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    };
+    match ident.as_deref() {
+        Some("String") => {
+            let Some(len) = lens.next() else {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`String` fields need a `#[max_len(..)]` attribute",
+                ));
+            };
+            Ok(quote!(#len_prefix + (#len)))
+        }
+        Some("Vec") => {
+            let Some(len) = lens.next() else {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`Vec` fields need a `#[max_len(..)]` attribute",
+                ));
+            };
+            let elem =
+                inner_ty(ty).ok_or_else(|| syn::Error::new_spanned(ty, "Expected `Vec<T>`"))?;
+            let elem = ty_max_size(elem, lens, len_prefix)?;
+            Ok(quote!(#len_prefix + (#len) * (#elem)))
+        }
+        Some("Option") => {
+            let inner =
+                inner_ty(ty).ok_or_else(|| syn::Error::new_spanned(ty, "Expected `Option<T>`"))?;
+            let inner = ty_max_size(inner, lens, len_prefix)?;
+            Ok(quote!(1 + (#inner)))
+        }
+        _ => Ok(quote!(<#ty as MaxSize>::MAX_SIZE)),
+    }
+}
+
+/// Maximum serialized size of the account fields, for Borsh and wincode layouts.
+///
+/// `len_prefix` is the size of the length written before `Vec` and `String` values.
+pub fn fields_max_size(fields: &Fields, len_prefix: usize) -> Result<TokenStream, syn::Error> {
+    let sizes = fields
+        .iter()
+        .map(|field| {
+            let lens = match max_len_attr(&field.attrs) {
+                Some(attr) => {
+                    attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?
+                }
+                None => Punctuated::new(),
+            };
+
+            let mut lens_iter = lens.iter();
+            let size = ty_max_size(&field.ty, &mut lens_iter, len_prefix)?;
+            if lens_iter.next().is_some() {
+                return Err(syn::Error::new_spanned(
+                    max_len_attr(&field.attrs),
+                    "Too many `max_len` values, expected one for each `Vec` or `String`",
+                ));
+            }
+            Ok(size)
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    Ok(quote!(0 #(+ #sizes)*))
+}
+
+/// Returns an error if a field has a `#[max_len(..)]` attribute.
+pub fn reject_max_len(fields: &Fields) -> Result<(), syn::Error> {
+    match fields.iter().find_map(|field| max_len_attr(&field.attrs)) {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            "`#[max_len]` is only supported on Borsh or wincode accounts",
+        )),
+        None => Ok(()),
+    }
+}
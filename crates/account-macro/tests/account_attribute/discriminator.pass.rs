@@ -0,0 +1,60 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(layout = 2)]
+pub struct Versioned {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(bytes = [7, 1])]
+pub struct Explicit {
+    pub value: [u8; 8],
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(compact = 1)]
+pub struct Compact {
+    pub flag: u8,
+    pub value: [u8; 8],
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(bytes = [1, 2, 3, 4])]
+pub struct Word {
+    pub value: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(anchor)]
+pub struct Counter {
+    pub value: u64,
+}
+
+pub fn main() {
+    assert_eq!(Versioned::DISCRIMINATOR[4], 2);
+    assert_eq!(Explicit::DISCRIMINATOR, [7, 1]);
+    assert_eq!(Explicit::SPACE, 2 + 8);
+    assert_eq!(Compact::DISCRIMINATOR.len(), 1);
+    assert_eq!(Compact::SPACE, 1 + 9);
+    assert_eq!(Word::SPACE, 4 + 4);
+    assert_eq!(
+        Counter::DISCRIMINATOR,
+        [255, 176, 4, 245, 188, 253, 124, 25]
+    );
+}
@@ -0,0 +1,38 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, TrailingStrategy},
+};
+
+pub const ID: Address = Address::new_from_array([1; 32]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(bytes = [7, 1])]
+pub struct Explicit {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(compact = 1)]
+pub struct Compact {
+    pub value: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, Copy, Clone)]
+#[repr(C)]
+pub struct Entry {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(compact = 2)]
+#[trailing(Entry)]
+pub struct Header {
+    pub flags: [u8; 8],
+}
+
+pub fn main() {}
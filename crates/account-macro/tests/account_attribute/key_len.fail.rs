@@ -0,0 +1,27 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    solana_instruction_view::{cpi::Seed, seeds},
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, FixedStr, SeedKey, StoredBump,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([1; 32]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct LongId {
+    #[key]
+    pub id: [u8; 48],
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct LongName {
+    #[key]
+    pub name: FixedStr<64>,
+}
+
+pub fn main() {}
@@ -0,0 +1,81 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    solana_instruction_view::{cpi::Seed, seeds},
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, FixedStr, SeedKey, StoredBump,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(NoUninit, AnyBitPattern, Copy, Clone)]
+#[repr(transparent)]
+pub struct Epoch(u64);
+
+impl SeedKey for Epoch {
+    const MAX_LEN: usize = 8;
+
+    type Bytes<'a> = [u8; 8];
+
+    fn seed_bytes(&self) -> Self::Bytes<'_> {
+        self.0.to_be_bytes()
+    }
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct AllKeys {
+    #[key]
+    pub amount: u128,
+    #[key]
+    pub delta: i64,
+    #[key]
+    pub hash: [u8; 32],
+    #[key]
+    pub name: FixedStr<16>,
+    #[key]
+    pub epoch: Epoch,
+    #[key]
+    pub small: i8,
+    pub _padding: [u8; 15],
+}
+
+#[derive(AccountState)]
+pub struct Flag {
+    #[key]
+    pub enabled: bool,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct OrderedKeys {
+    #[key(order = 1)]
+    pub authority: Address,
+    #[key(order = 0)]
+    pub id: u32,
+    pub _padding: [u8; 4],
+}
+
+pub fn main() {
+    let name = FixedStr::<16>::new("vault").unwrap();
+    let seeds = AllKeys::derive(&1, &-1, &[7; 32], &name, &Epoch(3), &-2);
+    let seeds = seeds.as_seeds();
+    assert_eq!(seeds.len(), 7);
+    assert_eq!(seeds[4], b"vault");
+    assert_eq!(seeds[5], 3u64.to_be_bytes());
+    assert_eq!(seeds[6], (-2i8).to_le_bytes());
+
+    let seeds = Flag::derive(&true);
+    assert_eq!(seeds.as_seeds()[1], [1]);
+
+    let authority = Address::new_from_array([1; 32]);
+    let seeds = OrderedKeys::derive(&5, &authority);
+    let seeds = seeds.as_seeds();
+    assert_eq!(seeds[1], 5u32.to_le_bytes());
+    assert_eq!(seeds[2], authority.as_ref());
+}
@@ -0,0 +1,33 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::Address,
+    typhoon_account_macro::*,
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(AccountState)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct MissingLen {
+    pub name: String,
+}
+
+#[derive(AccountState)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct TooManyLens {
+    #[max_len(4, 8)]
+    pub data: Vec<u8>,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Fixed {
+    #[max_len(4)]
+    pub data: [u8; 4],
+}
+
+fn main() {}
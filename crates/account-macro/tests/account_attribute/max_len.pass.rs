@@ -0,0 +1,49 @@
+use {
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{CheckOwner, DataStrategy, Discriminator, MaxSize, WincodeStrategy},
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(SchemaRead, SchemaWrite)]
+pub struct Entry {
+    pub amount: u64,
+    pub tag: [u8; 4],
+}
+
+impl MaxSize for Entry {
+    const MAX_SIZE: usize = 8 + 4;
+}
+
+#[derive(AccountState)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct Profile {
+    pub authority: Address,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(4, 16)]
+    pub tags: Vec<String>,
+    #[max_len(10)]
+    pub entries: Vec<Entry>,
+    #[max_len(8)]
+    pub nickname: Option<String>,
+    pub score: Option<u32>,
+}
+
+pub fn main() {
+    let name = 8 + 32;
+    let tags = 8 + 4 * (8 + 16);
+    let entries = 8 + 10 * 12;
+    let nickname = 1 + 8 + 8;
+    let score = 1 + 4;
+    assert_eq!(
+        Profile::MAX_SIZE,
+        32 + name + tags + entries + nickname + score
+    );
+    assert_eq!(Profile::SPACE, 8 + Profile::MAX_SIZE);
+}
@@ -0,0 +1,87 @@
+use {
+    bytemuck::{bytes_of, AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    solana_program_error::ProgramError,
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        Accessor, BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, Migratable,
+        MigrateFrom,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(name = "Counter", layout = 1)]
+pub struct CounterV1 {
+    pub count: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(name = "Counter", layout = 2)]
+pub struct CounterV2 {
+    pub count: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(layout = 3)]
+#[migrate_from(CounterV1, CounterV2)]
+pub struct Counter {
+    pub count: u64,
+    pub authority: Address,
+}
+
+impl MigrateFrom<CounterV1> for Counter {
+    fn migrate_from(previous: &CounterV1) -> Self {
+        Counter {
+            count: previous.count as u64,
+            authority: Address::default(),
+        }
+    }
+}
+
+impl MigrateFrom<CounterV2> for Counter {
+    fn migrate_from(previous: &CounterV2) -> Self {
+        Counter {
+            count: previous.count,
+            authority: Address::default(),
+        }
+    }
+}
+
+#[repr(C, align(8))]
+struct Aligned<const N: usize>([u8; N]);
+
+fn account_data<T: Discriminator + NoUninit, const N: usize>(value: &T) -> Aligned<N> {
+    let mut data = [0; N];
+    data[..T::DISCRIMINATOR.len()].copy_from_slice(T::DISCRIMINATOR);
+    data[T::DISCRIMINATOR.len()..].copy_from_slice(bytes_of(value));
+    Aligned(data)
+}
+
+pub fn main() {
+    let v1 = account_data::<_, 12>(&CounterV1 { count: 7 });
+    assert!(Counter::is_known_layout(&v1.0));
+    assert_eq!(Counter::migrate(&v1.0).unwrap().unwrap().count, 7);
+
+    let v2 = account_data::<_, 16>(&CounterV2 { count: 9 });
+    assert_eq!(Counter::migrate(&v2.0).unwrap().unwrap().count, 9);
+
+    let latest = account_data::<_, { 8 + 40 }>(&Counter {
+        count: 1,
+        authority: Address::default(),
+    });
+    assert!(Counter::migrate(&latest.0).unwrap().is_none());
+
+    assert!(!Counter::is_known_layout(&[0; 16]));
+    assert_eq!(
+        Counter::migrate(&[0; 16]).err(),
+        Some(ProgramError::InvalidAccountData)
+    );
+}
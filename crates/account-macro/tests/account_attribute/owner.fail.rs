@@ -0,0 +1,23 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::Address,
+    typhoon_account_macro::*,
+};
+
+pub const ID: Address = Address::new_from_array([0; 32]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[owner(any_of = [])]
+pub struct Empty {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[owner(any_of = ID)]
+pub struct NotArray {
+    pub value: u64,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator},
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+pub mod oracle {
+    use solana_address::Address;
+
+    pub const ID: Address = Address::new_from_array([1; 32]);
+    pub const ID_V2: Address = Address::new_from_array([2; 32]);
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[owner(oracle::ID)]
+#[discriminator(bytes = [0xd4, 0xc3, 0xb2, 0xa1, 0x00, 0x00, 0x00, 0x01])]
+pub struct PriceFeed {
+    pub price: i64,
+    pub exponent: i32,
+    pub padding: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[owner(any_of = [oracle::ID, oracle::ID_V2, crate::ID])]
+pub struct Shared {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Local {
+    pub value: u64,
+}
+
+pub fn main() {
+    assert!(PriceFeed::owned_by(&oracle::ID));
+    assert_eq!(PriceFeed::SPACE, 8 + 16);
+    assert!(!PriceFeed::owned_by(&ID));
+
+    assert!(Shared::owned_by(&oracle::ID));
+    assert!(Shared::owned_by(&oracle::ID_V2));
+    assert!(Shared::owned_by(&ID));
+    assert!(!Shared::owned_by(&Address::default()));
+
+    assert!(Local::owned_by(&ID));
+    assert!(!Local::owned_by(&oracle::ID));
+}
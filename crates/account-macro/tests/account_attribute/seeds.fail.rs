@@ -0,0 +1,31 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::Address,
+    typhoon_account_macro::*,
+};
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"vault", no_prefix)]
+pub struct Vault {
+    #[key]
+    pub authority: Address,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(no_prefix)]
+pub struct Empty {
+    pub value: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct WideBump {
+    #[key]
+    pub authority: Address,
+    #[bump]
+    pub bump: u64,
+}
+
+pub fn main() {}
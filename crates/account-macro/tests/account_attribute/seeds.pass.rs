@@ -0,0 +1,94 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    solana_instruction_view::{cpi::Seed, seeds},
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, SeedKey, StoredBump,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+pub const VERSION: u16 = 2;
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"vault")]
+pub struct Vault {
+    #[key]
+    pub authority: Address,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(no_prefix)]
+pub struct Position {
+    #[key]
+    pub owner: Address,
+    #[key]
+    pub id: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"pool", constant = &VERSION.to_le_bytes())]
+pub struct Pool {
+    #[key]
+    pub mint: Address,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"config")]
+pub struct Config {
+    pub fee: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Treasury {
+    #[key]
+    pub authority: Address,
+    pub amount: u64,
+    #[bump]
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+pub fn main() {
+    let authority = Address::new_from_array([1; 32]);
+
+    let seeds = Vault::derive(&authority);
+    assert_eq!(seeds.as_seeds(), [b"vault".as_slice(), authority.as_ref()]);
+
+    let seeds = Position::derive(&authority, &7);
+    assert_eq!(seeds.as_seeds(), [authority.as_ref(), &7u64.to_le_bytes()]);
+
+    let seeds = Pool::derive(&authority);
+    let seeds = seeds.seeds_with_bump(&[255]);
+    assert_eq!(seeds[0], b"pool");
+    assert_eq!(seeds[1], 2u16.to_le_bytes());
+    assert_eq!(seeds[2], authority.as_ref());
+    assert_eq!(seeds[3], [255]);
+
+    let seeds = Config::derive();
+    assert_eq!(seeds.as_seeds(), [b"config".as_slice()]);
+    assert!(!Config::HAS_STORED_BUMP);
+
+    let treasury = Treasury {
+        authority,
+        amount: 0,
+        bump: 254,
+        _padding: [0; 7],
+    };
+    assert!(Treasury::HAS_STORED_BUMP);
+    assert_eq!(treasury.stored_bump(), Some(254));
+
+    let mut data = [0; 8 + core::mem::size_of::<Treasury>()];
+    Treasury::write_stored_bump(&mut data, 253);
+    assert_eq!(data[8 + 40], 253);
+}
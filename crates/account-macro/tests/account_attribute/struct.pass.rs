@@ -3,7 +3,9 @@ use {
     solana_address::{address_eq, Address},
     solana_instruction_view::{cpi::Seed, seeds},
     typhoon_account_macro::*,
-    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator},
+    typhoon_traits::{
+        BytemuckStrategy, CheckOwner, DataStrategy, Discriminator, SeedKey, StoredBump,
+    },
 };
 
 pub const ID: Address = Address::new_from_array([
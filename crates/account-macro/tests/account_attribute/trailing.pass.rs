@@ -0,0 +1,59 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    solana_address::{address_eq, Address},
+    typhoon_account_macro::*,
+    typhoon_traits::{
+        Accessor, CheckOwner, DataStrategy, Discriminator, MutAccessor, TrailingStrategy,
+    },
+};
+
+pub const ID: Address = Address::new_from_array([
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+]);
+
+#[derive(NoUninit, AnyBitPattern, Copy, Clone)]
+#[repr(C)]
+pub struct Order {
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[trailing(Order)]
+pub struct OrderBook {
+    pub authority: Address,
+    pub sequence: u64,
+}
+
+#[repr(C, align(8))]
+struct Aligned<const N: usize>([u8; N]);
+
+pub fn main() {
+    assert_eq!(OrderBook::SPACE, 8 + 40);
+
+    let mut body = Aligned([0; 40 + 2 * 16]);
+    body.0[32] = 7;
+    body.0[40] = 10;
+    body.0[64] = 3;
+
+    let book = <<OrderBook as DataStrategy>::Strategy as Accessor<'_, OrderBook>>::access(&body.0)
+        .unwrap();
+    assert_eq!(book.sequence, 7);
+    assert_eq!(book.entries.len(), 2);
+    assert_eq!(book.entries[0].price, 10);
+    assert_eq!(book.entries[1].quantity, 3);
+
+    let book =
+        <TrailingStrategy<Order> as MutAccessor<'_, OrderBook>>::access_mut(&mut body.0).unwrap();
+    book.sequence += 1;
+    book.entries[1].price = 11;
+    assert_eq!(body.0[32], 8);
+    assert_eq!(body.0[56], 11);
+
+    assert!(
+        <TrailingStrategy<Order> as Accessor<'_, OrderBook>>::access(&body.0[..40 + 8]).is_err()
+    );
+    assert!(<TrailingStrategy<Order> as Accessor<'_, OrderBook>>::access(&body.0[..32]).is_err());
+}
@@ -1,6 +1,7 @@
 use {
     crate::{
-        discriminator_matches, AccountData, FromAccountInfo, FromRaw, ReadableAccount, System,
+        discriminator_matches, is_closed_account, AccountData, FromAccountInfo, FromRaw,
+        ReadableAccount, System, CLOSED_ACCOUNT_DISCRIMINATOR,
     },
     core::marker::PhantomData,
     pinocchio::hint::unlikely,
@@ -26,12 +27,22 @@ where
     fn try_from_info(info: &'a AccountView) -> Result<Self, Error> {
         // Check data length first - this is the cheapest check and most likely to fail
         if unlikely(info.data_len() < T::DISCRIMINATOR.len()) {
-            return Err(ProgramError::AccountDataTooSmall.into());
+            return Err(closed_or(info, ProgramError::AccountDataTooSmall.into()));
         }
 
         // Validate discriminator using optimized comparison for small discriminators
         if unlikely(!discriminator_matches::<T>(info)) {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            return Err(closed_or(
+                info,
+                ErrorCode::AccountDiscriminatorMismatch.into(),
+            ));
+        }
+
+        // Shorter discriminators can be a prefix of the sentinel of closed accounts
+        if T::DISCRIMINATOR.len() < CLOSED_ACCOUNT_DISCRIMINATOR.len()
+            && unlikely(is_closed_account(info))
+        {
+            return Err(ErrorCode::AccountClosed.into());
         }
 
         let owner = unsafe { info.owner() };
@@ -56,6 +67,16 @@ where
     }
 }
 
+/// Reports closed accounts with `ErrorCode::AccountClosed` instead of `err`.
+#[cold]
+fn closed_or(info: &AccountView, err: Error) -> Error {
+    if is_closed_account(info) {
+        ErrorCode::AccountClosed.into()
+    } else {
+        err
+    }
+}
+
 impl<'a, T> From<Account<'a, T>> for &'a AccountView
 where
     T: Discriminator,
@@ -96,3 +117,105 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::account::{RuntimeAccount, NOT_BORROWED},
+        solana_address::Address,
+    };
+
+    /// A runtime account followed by its data, as laid out by the loader.
+    #[repr(C)]
+    struct RawAccount<const N: usize> {
+        account: RuntimeAccount,
+        data: [u8; N],
+    }
+
+    impl<const N: usize> RawAccount<N> {
+        fn new(data: [u8; N]) -> Self {
+            RawAccount {
+                account: RuntimeAccount {
+                    borrow_state: NOT_BORROWED,
+                    owner: OWNER,
+                    lamports: 1,
+                    data_len: N as u64,
+                    ..RuntimeAccount::default()
+                },
+                data,
+            }
+        }
+
+        fn view(&mut self) -> AccountView {
+            unsafe { AccountView::new_unchecked(&mut self.account) }
+        }
+    }
+
+    const OWNER: Address = Address::new_from_array([1; 32]);
+
+    macro_rules! state {
+        ($name:ident, $discriminator:expr) => {
+            struct $name;
+
+            impl Discriminator for $name {
+                const DISCRIMINATOR: &'static [u8] = &$discriminator;
+            }
+
+            impl CheckOwner for $name {
+                fn owned_by(program_id: &Address) -> bool {
+                    *program_id == OWNER
+                }
+            }
+        };
+    }
+
+    state!(Short, [1, 2]);
+    state!(SentinelPrefix, [255, 255]);
+    state!(Long, [1; 16]);
+
+    fn try_from<T: CheckOwner + Discriminator>(info: &AccountView) -> Option<ProgramError> {
+        Account::<T>::try_from_info(info)
+            .err()
+            .map(ProgramError::from)
+    }
+
+    #[test]
+    fn closed_account() {
+        let mut closed = RawAccount::new(CLOSED_ACCOUNT_DISCRIMINATOR);
+        let mut longer = RawAccount::new([255; 9]);
+        let mut open = RawAccount::new([255, 255, 255, 255, 255, 255, 255, 0]);
+
+        assert!(is_closed_account(&closed.view()));
+        assert!(!is_closed_account(&longer.view()));
+        assert!(!is_closed_account(&open.view()));
+    }
+
+    #[test]
+    fn closed_account_is_rejected() {
+        let mut closed = RawAccount::new(CLOSED_ACCOUNT_DISCRIMINATOR);
+        let closed = closed.view();
+        let account_closed = Some(ErrorCode::AccountClosed.into());
+
+        assert_eq!(try_from::<Short>(&closed), account_closed);
+        assert_eq!(try_from::<SentinelPrefix>(&closed), account_closed);
+        assert_eq!(try_from::<Long>(&closed), account_closed);
+    }
+
+    #[test]
+    fn open_account_errors_are_kept() {
+        let mut short = RawAccount::new([1, 2, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(try_from::<Short>(&short.view()), None);
+        assert_eq!(
+            try_from::<SentinelPrefix>(&short.view()),
+            Some(ErrorCode::AccountDiscriminatorMismatch.into())
+        );
+        assert_eq!(
+            try_from::<Long>(&short.view()),
+            Some(ProgramError::AccountDataTooSmall)
+        );
+
+        let mut sentinel_prefix = RawAccount::new([255; 9]);
+        assert_eq!(try_from::<SentinelPrefix>(&sentinel_prefix.view()), None);
+    }
+}
@@ -3,7 +3,9 @@ mod mutable;
 mod program;
 mod signer;
 mod system;
+mod sysvar;
 mod unchecked;
+mod versioned;
 
 pub use {
     account::*,
@@ -11,5 +13,7 @@ pub use {
     program::*,
     signer::{Signer, SignerCheck, UncheckedSigner},
     system::*,
+    sysvar::*,
     unchecked::*,
+    versioned::*,
 };
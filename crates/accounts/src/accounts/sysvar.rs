@@ -0,0 +1,61 @@
+use {
+    crate::{FromAccountInfo, ReadableAccount, SysvarAccount},
+    core::marker::PhantomData,
+    pinocchio::hint::unlikely,
+    solana_account_view::AccountView,
+    solana_address::address_eq,
+    solana_program_error::ProgramError,
+    typhoon_errors::Error,
+};
+
+///
+/// Checks:
+/// * `account_info.key == T::ID`
+pub struct Sysvar<'a, T> {
+    info: &'a AccountView,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for Sysvar<'a, T>
+where
+    T: SysvarAccount,
+{
+    #[inline(always)]
+    fn try_from_info(info: &'a AccountView) -> Result<Self, Error> {
+        if unlikely(!address_eq(info.address(), &T::ID)) {
+            return Err(ProgramError::InvalidArgument.into());
+        }
+
+        Ok(Sysvar {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> Sysvar<'_, T>
+where
+    T: SysvarAccount,
+{
+    /// Borrows the sysvar data and returns its zero-copy view.
+    #[inline(always)]
+    pub fn view(&self) -> Result<T::View<'_>, ProgramError> {
+        T::view(self.info.try_borrow()?)
+    }
+}
+
+impl<'a, T> From<Sysvar<'a, T>> for &'a AccountView {
+    #[inline(always)]
+    fn from(value: Sysvar<'a, T>) -> Self {
+        value.info
+    }
+}
+
+impl<T> AsRef<AccountView> for Sysvar<'_, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &AccountView {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for Sysvar<'_, T> {}
@@ -0,0 +1,93 @@
+use {
+    crate::{discriminator_matches, FromAccountInfo, FromRaw, ReadableAccount, System},
+    core::marker::PhantomData,
+    pinocchio::hint::unlikely,
+    solana_account_view::AccountView,
+    solana_program_error::ProgramError,
+    typhoon_errors::{Error, ErrorCode},
+    typhoon_traits::{CheckOwner, CheckProgramId, Migratable},
+};
+
+/// Account stored with the latest layout of `T` or any layout listed in its `#[migrate_from(..)]`.
+///
+/// The data is not accessible until the account is migrated, see `MigrateAccount`.
+pub struct VersionedAccount<'a, T>
+where
+    T: Migratable,
+{
+    info: &'a AccountView,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> VersionedAccount<'_, T>
+where
+    T: Migratable,
+{
+    /// Returns `true` if the account already uses the latest layout.
+    #[inline(always)]
+    pub fn is_latest(&self) -> bool {
+        self.info.data_len() >= T::DISCRIMINATOR.len() && discriminator_matches::<T>(self.info)
+    }
+}
+
+impl<'a, T> FromAccountInfo<'a> for VersionedAccount<'a, T>
+where
+    T: CheckOwner + Migratable,
+{
+    #[inline(always)]
+    fn try_from_info(info: &'a AccountView) -> Result<Self, Error> {
+        let data = info.try_borrow()?;
+        if unlikely(!T::is_known_layout(&data)) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        drop(data);
+
+        let owner = unsafe { info.owner() };
+        if unlikely(!T::owned_by(owner)) {
+            return Err(ProgramError::InvalidAccountOwner.into());
+        }
+
+        if unlikely(System::address_eq(owner)) && info.lamports() == 0 {
+            return Err(ProgramError::UninitializedAccount.into());
+        }
+
+        Ok(VersionedAccount {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> From<VersionedAccount<'a, T>> for &'a AccountView
+where
+    T: Migratable,
+{
+    #[inline(always)]
+    fn from(value: VersionedAccount<'a, T>) -> Self {
+        value.info
+    }
+}
+
+impl<T> AsRef<AccountView> for VersionedAccount<'_, T>
+where
+    T: Migratable,
+{
+    #[inline(always)]
+    fn as_ref(&self) -> &AccountView {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for VersionedAccount<'_, T> where T: Migratable {}
+
+impl<'a, T> FromRaw<'a> for VersionedAccount<'a, T>
+where
+    T: Migratable,
+{
+    fn from_raw(info: &'a AccountView) -> Self {
+        Self {
+            info,
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -1,5 +1,17 @@
 use {solana_account_view::AccountView, typhoon_traits::Discriminator};
 
+/// Sentinel discriminator left in the data of accounts closed with
+/// `CloseAccount::close_with_sentinel`.
+pub const CLOSED_ACCOUNT_DISCRIMINATOR: [u8; 8] = [255; 8];
+
+/// Returns `true` if the account only holds the [`CLOSED_ACCOUNT_DISCRIMINATOR`].
+#[inline(always)]
+pub fn is_closed_account(info: &AccountView) -> bool {
+    // SAFETY: The account data holds exactly 8 bytes when the first condition holds.
+    info.data_len() == CLOSED_ACCOUNT_DISCRIMINATOR.len()
+        && unsafe { (info.data_ptr() as *const u64).read_unaligned() } == u64::MAX
+}
+
 /// Discriminator matching with length-optimized comparison strategies.
 /// Uses different comparison methods based on discriminator length:
 /// - `1-8` bytes: Unaligned integer reads for maximum performance
@@ -1,6 +1,10 @@
 #![no_std]
 
-pub use {accounts::*, discriminator::*, programs::*};
+#[cfg(feature = "bytemuck")]
+pub use lookup_table::*;
+#[cfg(feature = "bytemuck")]
+pub use stake::*;
+pub use {accounts::*, discriminator::*, programs::*, state::*, sysvars::*};
 use {
     solana_account_view::{AccountView, Ref, RefMut},
     solana_address::Address,
@@ -11,7 +15,13 @@ use {
 
 mod accounts;
 mod discriminator;
+#[cfg(feature = "bytemuck")]
+mod lookup_table;
 mod programs;
+#[cfg(feature = "bytemuck")]
+mod stake;
+mod state;
+mod sysvars;
 
 pub trait FromAccountInfo<'a>: Sized {
     fn try_from_info(info: &'a AccountView) -> Result<Self, Error>;
@@ -71,10 +81,9 @@ pub trait AccountData: ReadableAccount {
 
 pub trait ReadableAccountData: AccountData {
     #[inline(always)]
-    fn data(&self) -> Result<Ref<'_, Self::Data>, ProgramError>
+    fn data<V: ?Sized>(&self) -> Result<Ref<'_, V>, ProgramError>
     where
-        <Self::Data as DataStrategy>::Strategy:
-            for<'a> Accessor<'a, Self::Data, Data = &'a Self::Data>,
+        <Self::Data as DataStrategy>::Strategy: for<'a> Accessor<'a, Self::Data, Data = &'a V>,
     {
         Ref::try_map(self.as_ref().try_borrow()?, |data| {
             <<Self::Data as DataStrategy>::Strategy as Accessor<'_, Self::Data>>::access(
@@ -89,24 +98,7 @@ pub trait ReadableAccountData: AccountData {
     where
         <Self::Data as DataStrategy>::Strategy: for<'a> Accessor<'a, Self::Data, Data = Self::Data>,
     {
-        self.as_ref().check_borrow()?;
-        let data = unsafe { self.as_ref().borrow_unchecked() };
-        <<Self::Data as DataStrategy>::Strategy as Accessor<'_, Self::Data>>::access(
-            &data[Self::Data::DISCRIMINATOR.len()..],
-        )
-    }
-
-    #[inline(always)]
-    fn data_unchecked(
-        &self,
-    ) -> Result<
-        <<Self::Data as DataStrategy>::Strategy as Accessor<'_, Self::Data>>::Data,
-        ProgramError,
-    >
-    where
-        <Self::Data as DataStrategy>::Strategy: for<'a> Accessor<'a, Self::Data>,
-    {
-        let data = unsafe { self.as_ref().borrow_unchecked() };
+        let data = self.as_ref().try_borrow()?;
         <<Self::Data as DataStrategy>::Strategy as Accessor<'_, Self::Data>>::access(
             &data[Self::Data::DISCRIMINATOR.len()..],
         )
@@ -117,10 +109,10 @@ impl<T> ReadableAccountData for T where T: AccountData {}
 
 pub trait WritableAccountData: AccountData + WritableAccount {
     #[inline(always)]
-    fn mut_data(&self) -> Result<RefMut<'_, Self::Data>, Error>
+    fn mut_data<V: ?Sized>(&self) -> Result<RefMut<'_, V>, Error>
     where
         <Self::Data as DataStrategy>::Strategy:
-            for<'a> MutAccessor<'a, Self::Data, Data = &'a mut Self::Data>,
+            for<'a> MutAccessor<'a, Self::Data, Data = &'a mut V>,
     {
         RefMut::try_map(self.as_ref().try_borrow_mut()?, |data| {
             <<Self::Data as DataStrategy>::Strategy as MutAccessor<'_, Self::Data>>::access_mut(
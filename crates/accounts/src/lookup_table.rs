@@ -0,0 +1,88 @@
+use {
+    crate::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    bytemuck::{Pod, Zeroable},
+    solana_address::{address_eq, Address},
+    typhoon_traits::{CheckOwner, DataStrategy, Discriminator, Trailing, TrailingStrategy},
+};
+
+/// The maximum number of addresses stored in a lookup table.
+pub const LOOKUP_TABLE_MAX_ADDRESSES: usize = 256;
+
+/// Header of an Address Lookup Table account, followed by the addresses of the table.
+///
+/// `Account<LookupTable>` reads the whole table as a [`LookupTableData`] through
+/// `data()`, without copying the addresses.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LookupTable {
+    deactivation_slot: [u8; 8],
+    last_extended_slot: [u8; 8],
+    last_extended_slot_start_index: u8,
+    has_authority: u8,
+    authority: Address,
+    _padding: [u8; 2],
+}
+
+// SAFETY: `LookupTable` only holds bytes, so it has no padding and an alignment of 1.
+unsafe impl Zeroable for LookupTable {}
+unsafe impl Pod for LookupTable {}
+
+/// A lookup table header followed by its addresses.
+pub type LookupTableData = Trailing<LookupTable, Address>;
+
+impl LookupTable {
+    /// The slot at which the table was deactivated, `u64::MAX` while it is active.
+    #[inline(always)]
+    pub fn deactivation_slot(&self) -> u64 {
+        u64::from_le_bytes(self.deactivation_slot)
+    }
+
+    /// The slot at which the table was last extended.
+    #[inline(always)]
+    pub fn last_extended_slot(&self) -> u64 {
+        u64::from_le_bytes(self.last_extended_slot)
+    }
+
+    /// The index of the first address added in the last extended slot.
+    #[inline(always)]
+    pub fn last_extended_slot_start_index(&self) -> u8 {
+        self.last_extended_slot_start_index
+    }
+
+    /// The authority allowed to modify the table, `None` once frozen.
+    #[inline(always)]
+    pub fn authority(&self) -> Option<&Address> {
+        (self.has_authority != 0).then_some(&self.authority)
+    }
+
+    /// Returns `true` if the table was deactivated.
+    #[inline(always)]
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivation_slot() != u64::MAX
+    }
+}
+
+impl DataStrategy for LookupTable {
+    type Strategy = TrailingStrategy<Address>;
+}
+
+impl Discriminator for LookupTable {
+    // The `LookupTable` variant of the program state enum.
+    const DISCRIMINATOR: &'static [u8] = &[1, 0, 0, 0];
+}
+
+impl CheckOwner for LookupTable {
+    #[inline(always)]
+    fn owned_by(program_id: &Address) -> bool {
+        address_eq(program_id, &ADDRESS_LOOKUP_TABLE_PROGRAM_ID)
+    }
+}
+
+/// Derives the address of the lookup table created by `authority` at `recent_slot`.
+#[inline]
+pub fn find_lookup_table_address(authority: &Address, recent_slot: u64) -> (Address, u8) {
+    Address::find_program_address(
+        &[authority.as_ref(), &recent_slot.to_le_bytes()],
+        &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    )
+}
@@ -16,3 +16,42 @@ impl CheckProgramId for System {
         }
     }
 }
+
+/// The address of the SPL Memo program (v2).
+pub const MEMO_PROGRAM_ID: Address =
+    Address::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+pub struct Memo;
+
+impl CheckProgramId for Memo {
+    #[inline(always)]
+    fn address_eq(program_id: &Address) -> bool {
+        solana_address::address_eq(program_id, &MEMO_PROGRAM_ID)
+    }
+}
+
+/// The address of the Address Lookup Table program.
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Address =
+    Address::from_str_const("AddressLookupTab1e1111111111111111111111111");
+
+pub struct AddressLookupTable;
+
+impl CheckProgramId for AddressLookupTable {
+    #[inline(always)]
+    fn address_eq(program_id: &Address) -> bool {
+        solana_address::address_eq(program_id, &ADDRESS_LOOKUP_TABLE_PROGRAM_ID)
+    }
+}
+
+/// The address of the Stake program.
+pub const STAKE_PROGRAM_ID: Address =
+    Address::from_str_const("Stake11111111111111111111111111111111111111");
+
+pub struct StakeProgram;
+
+impl CheckProgramId for StakeProgram {
+    #[inline(always)]
+    fn address_eq(program_id: &Address) -> bool {
+        solana_address::address_eq(program_id, &STAKE_PROGRAM_ID)
+    }
+}
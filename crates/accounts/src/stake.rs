@@ -0,0 +1,169 @@
+use {
+    crate::STAKE_PROGRAM_ID,
+    bytemuck::{Pod, Zeroable},
+    solana_address::{address_eq, Address},
+    typhoon_traits::{BytemuckStrategy, CheckOwner, DataStrategy, Discriminator},
+};
+
+/// The state of a stake account, stored in its first four bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StakeState {
+    Uninitialized,
+    Initialized,
+    Stake,
+    RewardsPool,
+}
+
+/// Zero-copy view over a Stake program account.
+///
+/// Getters of the meta and delegation fields return `None` when the account
+/// is not in a state that holds them.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StakeAccount {
+    state: [u8; 4],
+    rent_exempt_reserve: [u8; 8],
+    staker: Address,
+    withdrawer: Address,
+    lockup_unix_timestamp: [u8; 8],
+    lockup_epoch: [u8; 8],
+    lockup_custodian: Address,
+    voter: Address,
+    stake: [u8; 8],
+    activation_epoch: [u8; 8],
+    deactivation_epoch: [u8; 8],
+    warmup_cooldown_rate: [u8; 8],
+    credits_observed: [u8; 8],
+    stake_flags: u8,
+    _padding: [u8; 3],
+}
+
+// SAFETY: `StakeAccount` only holds bytes, so it has no padding and an alignment of 1.
+unsafe impl Zeroable for StakeAccount {}
+unsafe impl Pod for StakeAccount {}
+
+/// The lockup of a stake account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lockup<'a> {
+    /// Unix timestamp at which the lockup expires.
+    pub unix_timestamp: i64,
+    /// Epoch at which the lockup expires.
+    pub epoch: u64,
+    /// Custodian allowed to bypass the lockup.
+    pub custodian: &'a Address,
+}
+
+impl StakeAccount {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    /// The state of the account, `None` if the tag is unknown.
+    #[inline(always)]
+    pub fn state(&self) -> Option<StakeState> {
+        match u32::from_le_bytes(self.state) {
+            0 => Some(StakeState::Uninitialized),
+            1 => Some(StakeState::Initialized),
+            2 => Some(StakeState::Stake),
+            3 => Some(StakeState::RewardsPool),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn has_meta(&self) -> bool {
+        matches!(
+            self.state(),
+            Some(StakeState::Initialized | StakeState::Stake)
+        )
+    }
+
+    #[inline(always)]
+    fn is_delegated(&self) -> bool {
+        self.state() == Some(StakeState::Stake)
+    }
+
+    /// The lamports kept in the account to stay rent exempt.
+    #[inline(always)]
+    pub fn rent_exempt_reserve(&self) -> Option<u64> {
+        self.has_meta()
+            .then(|| u64::from_le_bytes(self.rent_exempt_reserve))
+    }
+
+    /// The authority allowed to delegate and deactivate the stake.
+    #[inline(always)]
+    pub fn staker(&self) -> Option<&Address> {
+        self.has_meta().then_some(&self.staker)
+    }
+
+    /// The authority allowed to withdraw from the account.
+    #[inline(always)]
+    pub fn withdrawer(&self) -> Option<&Address> {
+        self.has_meta().then_some(&self.withdrawer)
+    }
+
+    /// The lockup of the account.
+    #[inline(always)]
+    pub fn lockup(&self) -> Option<Lockup<'_>> {
+        self.has_meta().then(|| Lockup {
+            unix_timestamp: i64::from_le_bytes(self.lockup_unix_timestamp),
+            epoch: u64::from_le_bytes(self.lockup_epoch),
+            custodian: &self.lockup_custodian,
+        })
+    }
+
+    /// The vote account the stake is delegated to.
+    #[inline(always)]
+    pub fn voter(&self) -> Option<&Address> {
+        self.is_delegated().then_some(&self.voter)
+    }
+
+    /// The delegated amount of lamports.
+    #[inline(always)]
+    pub fn delegated_stake(&self) -> Option<u64> {
+        self.is_delegated().then(|| u64::from_le_bytes(self.stake))
+    }
+
+    /// The epoch at which the stake was activated.
+    #[inline(always)]
+    pub fn activation_epoch(&self) -> Option<u64> {
+        self.is_delegated()
+            .then(|| u64::from_le_bytes(self.activation_epoch))
+    }
+
+    /// The epoch at which the stake was deactivated, `u64::MAX` while it is active.
+    #[inline(always)]
+    pub fn deactivation_epoch(&self) -> Option<u64> {
+        self.is_delegated()
+            .then(|| u64::from_le_bytes(self.deactivation_epoch))
+    }
+
+    /// The vote credits observed when the rewards were last paid out.
+    #[inline(always)]
+    pub fn credits_observed(&self) -> Option<u64> {
+        self.is_delegated()
+            .then(|| u64::from_le_bytes(self.credits_observed))
+    }
+
+    /// The raw stake flags.
+    #[inline(always)]
+    pub fn stake_flags(&self) -> u8 {
+        self.stake_flags
+    }
+}
+
+impl DataStrategy for StakeAccount {
+    type Strategy = BytemuckStrategy;
+}
+
+impl Discriminator for StakeAccount {
+    // Every state shares the same layout, the tag is read by `state()`.
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+impl CheckOwner for StakeAccount {
+    #[inline(always)]
+    fn owned_by(program_id: &Address) -> bool {
+        address_eq(program_id, &STAKE_PROGRAM_ID)
+    }
+}
+
+const _: () = assert!(StakeAccount::LEN == 200);
@@ -0,0 +1,106 @@
+#[cfg(feature = "bytemuck")]
+use typhoon_traits::{BytemuckStrategy, Trailing, TrailingStrategy};
+use {
+    crate::AccountData,
+    solana_account_view::{AccountView, Ref},
+    solana_program_error::ProgramError,
+    typhoon_traits::{Accessor, DataStrategy, Discriminator, WincodeStrategy},
+};
+
+/// Parsed state of an account, read through a checked borrow of its data.
+pub type StateOf<'a, A> = <<<A as AccountData>::Data as DataStrategy>::Strategy as StateStrategy<
+    'a,
+    <A as AccountData>::Data,
+>>::State;
+
+/// Trait for strategies whose state can be read without aliasing the account data.
+///
+/// Zero-copy strategies return a [`Ref`] which keeps the data borrowed for as long as
+/// the state lives, others return an owned value parsed while the data is borrowed.
+pub trait StateStrategy<'a, T> {
+    /// The parsed state.
+    type State;
+
+    /// Reads the state of `info`, skipping the discriminator of `T`.
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError>;
+}
+
+/// Reads the state of the `A` account held by `info`.
+#[inline(always)]
+pub fn account_state<'a, A>(info: &'a AccountView) -> Result<StateOf<'a, A>, ProgramError>
+where
+    A: AccountData,
+    <A::Data as DataStrategy>::Strategy: StateStrategy<'a, A::Data>,
+{
+    <<A::Data as DataStrategy>::Strategy as StateStrategy<'a, A::Data>>::state(info)
+}
+
+/// Maps the data of `info` to the zero-copy view of the `S` strategy.
+#[inline(always)]
+pub fn map_state<'a, T, S, V>(info: &'a AccountView) -> Result<Ref<'a, V>, ProgramError>
+where
+    T: Discriminator,
+    S: for<'b> Accessor<'b, T, Data = &'b V>,
+    V: ?Sized,
+{
+    Ref::try_map(info.try_borrow()?, |data| {
+        S::access(&data[T::DISCRIMINATOR.len()..])
+    })
+    .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, T> StateStrategy<'a, T> for BytemuckStrategy
+where
+    T: Discriminator + 'a,
+    Self: for<'b> Accessor<'b, T, Data = &'b T>,
+{
+    type State = Ref<'a, T>;
+
+    #[inline(always)]
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError> {
+        map_state::<T, Self, T>(info)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, H, E> StateStrategy<'a, H> for TrailingStrategy<E>
+where
+    H: Discriminator + 'a,
+    E: 'a,
+    Self: for<'b> Accessor<'b, H, Data = &'b Trailing<H, E>>,
+{
+    type State = Ref<'a, Trailing<H, E>>;
+
+    #[inline(always)]
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError> {
+        map_state::<H, Self, Trailing<H, E>>(info)
+    }
+}
+
+impl<'a, T, C> StateStrategy<'a, T> for WincodeStrategy<true, C>
+where
+    T: Discriminator + 'a,
+    Self: for<'b> Accessor<'b, T, Data = &'b T>,
+{
+    type State = Ref<'a, T>;
+
+    #[inline(always)]
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError> {
+        map_state::<T, Self, T>(info)
+    }
+}
+
+impl<'a, T, C> StateStrategy<'a, T> for WincodeStrategy<false, C>
+where
+    T: Discriminator,
+    Self: for<'b> Accessor<'b, T, Data = T>,
+{
+    type State = T;
+
+    #[inline(always)]
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError> {
+        let data = info.try_borrow()?;
+        <Self as Accessor<'_, T>>::access(&data[T::DISCRIMINATOR.len()..])
+    }
+}
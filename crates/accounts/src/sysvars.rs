@@ -0,0 +1,297 @@
+use {
+    bytemuck::{try_cast_slice, try_from_bytes, Pod, Zeroable},
+    pinocchio::sysvars::{
+        clock::{Clock, CLOCK_ID},
+        instructions::{Instructions as InstructionsView, INSTRUCTIONS_ID},
+        rent::{Rent, RENT_ID},
+        slot_hashes::{SlotHashes as SlotHashesView, SLOTHASHES_ID},
+    },
+    solana_account_view::Ref,
+    solana_address::Address,
+    solana_program_error::ProgramError,
+};
+
+/// Trait for sysvars that can be read from their account with [`Sysvar`](crate::Sysvar).
+pub trait SysvarAccount {
+    /// The address of the sysvar account.
+    const ID: Address;
+
+    /// The zero-copy view of the sysvar data.
+    type View<'a>;
+
+    /// Parses the borrowed sysvar `data`.
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError>;
+}
+
+impl SysvarAccount for Clock {
+    const ID: Address = CLOCK_ID;
+
+    type View<'a> = Ref<'a, Clock>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        Ref::try_map(data, Clock::from_bytes).map_err(|(_, err)| err)
+    }
+}
+
+impl SysvarAccount for Rent {
+    const ID: Address = RENT_ID;
+
+    type View<'a> = Ref<'a, Rent>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        Ref::try_map(data, Rent::from_bytes).map_err(|(_, err)| err)
+    }
+}
+
+/// The `Instructions` sysvar, holding the instructions of the current transaction.
+pub struct Instructions;
+
+impl SysvarAccount for Instructions {
+    const ID: Address = INSTRUCTIONS_ID;
+
+    type View<'a> = InstructionsView<Ref<'a, [u8]>>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        // The current index is stored in the last 2 bytes, after the instruction count.
+        if data.len() < 4 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `data` is borrowed from the account checked against `INSTRUCTIONS_ID`.
+        Ok(unsafe { InstructionsView::new_unchecked(data) })
+    }
+}
+
+/// The `SlotHashes` sysvar, holding the most recent hashes of the slot's parent banks.
+pub struct SlotHashes;
+
+impl SysvarAccount for SlotHashes {
+    const ID: Address = SLOTHASHES_ID;
+
+    type View<'a> = SlotHashesView<Ref<'a, [u8]>>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        SlotHashesView::new(data)
+    }
+}
+
+/// The `EpochSchedule` sysvar, read in place from its bincode layout.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EpochSchedule {
+    slots_per_epoch: [u8; 8],
+    leader_schedule_slot_offset: [u8; 8],
+    warmup: u8,
+    first_normal_epoch: [u8; 8],
+    first_normal_slot: [u8; 8],
+}
+
+// SAFETY: `EpochSchedule` only holds bytes, so it has no padding and an alignment of 1.
+unsafe impl Zeroable for EpochSchedule {}
+unsafe impl Pod for EpochSchedule {}
+
+impl EpochSchedule {
+    /// The maximum number of slots in each epoch.
+    #[inline(always)]
+    pub fn slots_per_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.slots_per_epoch)
+    }
+
+    /// The number of slots before the beginning of an epoch to calculate its leader schedule.
+    #[inline(always)]
+    pub fn leader_schedule_slot_offset(&self) -> u64 {
+        u64::from_le_bytes(self.leader_schedule_slot_offset)
+    }
+
+    /// Whether epochs start short and grow.
+    #[inline(always)]
+    pub fn warmup(&self) -> bool {
+        self.warmup != 0
+    }
+
+    /// The first epoch after the warmup period.
+    #[inline(always)]
+    pub fn first_normal_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.first_normal_epoch)
+    }
+
+    /// The first slot after the warmup period.
+    #[inline(always)]
+    pub fn first_normal_slot(&self) -> u64 {
+        u64::from_le_bytes(self.first_normal_slot)
+    }
+}
+
+impl SysvarAccount for EpochSchedule {
+    const ID: Address = Address::from_str_const("SysvarEpochSchedu1e111111111111111111111111");
+
+    type View<'a> = Ref<'a, EpochSchedule>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        Ref::try_map(data, |data| {
+            data.get(..size_of::<EpochSchedule>())
+                .and_then(|data| try_from_bytes(data).ok())
+                .ok_or(ProgramError::InvalidAccountData)
+        })
+        .map_err(|(_, err)| err)
+    }
+}
+
+/// Zero-copy view of a sysvar holding a length-prefixed list of `E` entries.
+pub struct SysvarEntries<'a, E> {
+    data: Ref<'a, [E]>,
+}
+
+impl<'a, E: Pod> SysvarEntries<'a, E> {
+    /// Parses the `u64` entry count followed by the entries.
+    #[inline]
+    pub fn new(data: Ref<'a, [u8]>) -> Result<Self, ProgramError> {
+        let data = Ref::try_map(data, |data| {
+            let (len, entries) = data
+                .split_first_chunk::<8>()
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let len = usize::try_from(u64::from_le_bytes(*len))
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            len.checked_mul(size_of::<E>())
+                .and_then(|size| entries.get(..size))
+                .and_then(|entries| try_cast_slice(entries).ok())
+                .ok_or(ProgramError::InvalidAccountData)
+        })
+        .map_err(|(_, err)| err)?;
+
+        Ok(SysvarEntries { data })
+    }
+
+    /// Returns the entries.
+    #[inline(always)]
+    pub fn entries(&self) -> &[E] {
+        &self.data
+    }
+
+    /// Returns the number of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the entry at `index`.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&E> {
+        self.data.get(index)
+    }
+}
+
+/// An entry of the `StakeHistory` sysvar.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StakeHistoryEntry {
+    epoch: [u8; 8],
+    effective: [u8; 8],
+    activating: [u8; 8],
+    deactivating: [u8; 8],
+}
+
+// SAFETY: `StakeHistoryEntry` only holds bytes, so it has no padding and an alignment of 1.
+unsafe impl Zeroable for StakeHistoryEntry {}
+unsafe impl Pod for StakeHistoryEntry {}
+
+impl StakeHistoryEntry {
+    /// The epoch of the entry.
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// The effective stake at this epoch.
+    #[inline(always)]
+    pub fn effective(&self) -> u64 {
+        u64::from_le_bytes(self.effective)
+    }
+
+    /// The stake being activated at this epoch.
+    #[inline(always)]
+    pub fn activating(&self) -> u64 {
+        u64::from_le_bytes(self.activating)
+    }
+
+    /// The stake being deactivated at this epoch.
+    #[inline(always)]
+    pub fn deactivating(&self) -> u64 {
+        u64::from_le_bytes(self.deactivating)
+    }
+}
+
+impl SysvarEntries<'_, StakeHistoryEntry> {
+    /// Returns the entry of `epoch`, entries being sorted from the most recent epoch.
+    #[inline]
+    pub fn get_epoch(&self, epoch: u64) -> Option<&StakeHistoryEntry> {
+        self.data
+            .binary_search_by(|entry| epoch.cmp(&entry.epoch()))
+            .ok()
+            .map(|index| &self.data[index])
+    }
+}
+
+/// The `StakeHistory` sysvar, holding the stake activation of the recent epochs.
+pub struct StakeHistory;
+
+impl SysvarAccount for StakeHistory {
+    const ID: Address = Address::from_str_const("SysvarStakeHistory1111111111111111111111111");
+
+    type View<'a> = SysvarEntries<'a, StakeHistoryEntry>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        SysvarEntries::new(data)
+    }
+}
+
+/// An entry of the `RecentBlockhashes` sysvar.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RecentBlockhashesEntry {
+    blockhash: [u8; 32],
+    lamports_per_signature: [u8; 8],
+}
+
+// SAFETY: `RecentBlockhashesEntry` only holds bytes, so it has no padding and an alignment of 1.
+unsafe impl Zeroable for RecentBlockhashesEntry {}
+unsafe impl Pod for RecentBlockhashesEntry {}
+
+impl RecentBlockhashesEntry {
+    /// The blockhash of the entry.
+    #[inline(always)]
+    pub fn blockhash(&self) -> &[u8; 32] {
+        &self.blockhash
+    }
+
+    /// The fee per signature when the blockhash was recorded.
+    #[inline(always)]
+    pub fn lamports_per_signature(&self) -> u64 {
+        u64::from_le_bytes(self.lamports_per_signature)
+    }
+}
+
+/// The deprecated `RecentBlockhashes` sysvar, holding the most recent blockhashes.
+pub struct RecentBlockhashes;
+
+impl SysvarAccount for RecentBlockhashes {
+    const ID: Address = Address::from_str_const("SysvarRecentB1ockHashes11111111111111111111");
+
+    type View<'a> = SysvarEntries<'a, RecentBlockhashesEntry>;
+
+    #[inline(always)]
+    fn view(data: Ref<'_, [u8]>) -> Result<Self::View<'_>, ProgramError> {
+        SysvarEntries::new(data)
+    }
+}
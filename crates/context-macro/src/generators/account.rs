@@ -3,7 +3,7 @@ use {
     quote::{format_ident, quote},
     syn::{parse_quote, punctuated::Punctuated, Expr, Ident, Token},
     typhoon_syn::{
-        constraints::{ConstraintAddress, ConstraintAssert},
+        constraints::{ConstraintAddress, ConstraintAssert, ConstraintInitWithSeed},
         error,
         utils::{ContextExpr, SeedsExpr},
         InstructionAccount,
@@ -21,8 +21,14 @@ pub enum AccountType {
         authority: Option<Expr>,
         freeze_authority: Box<Option<Expr>>,
     },
+    Stake {
+        staker: Option<Expr>,
+        withdrawer: Option<Expr>,
+        rent_sysvar: Option<Ident>,
+    },
     Other {
         space: Option<Expr>,
+        init_value: Option<Expr>,
         targets: Vec<(Ident, Option<Expr>)>,
     },
 }
@@ -31,6 +37,13 @@ pub enum AccountType {
 pub struct InitContext {
     pub is_init_if_needed: bool,
     pub payer: Option<Ident>,
+    pub with_seed: Option<ConstraintInitWithSeed>,
+}
+
+#[derive(Default)]
+pub struct NextInstructionContext {
+    pub program: Option<Expr>,
+    pub discriminator: Option<Expr>,
 }
 
 #[derive(Default)]
@@ -41,6 +54,17 @@ pub struct PdaContext {
     pub program_id: Option<Expr>,
 }
 
+impl PdaContext {
+    /// Name of the constraint defining the PDA, used to trace failures.
+    fn constraint(&self) -> &'static str {
+        if self.is_seeded {
+            "seeded"
+        } else {
+            "seeds"
+        }
+    }
+}
+
 /// Controls how `get_pda` derives the PDA address and bump.
 enum PdaMode {
     /// Derive both address and bump. Uses `create_program_address` when bump is known.
@@ -55,6 +79,7 @@ struct AccountIdents {
     key: Ident,
     bump: Ident,
     state: Ident,
+    info: Ident,
 }
 
 impl AccountIdents {
@@ -63,6 +88,7 @@ impl AccountIdents {
             key: format_ident!("{}_key", name),
             bump: format_ident!("{}_bump", name),
             state: format_ident!("{}_state", name),
+            info: format_ident!("{}_info", name),
         }
     }
 }
@@ -75,6 +101,7 @@ pub struct AccountGenerator<'a> {
     pub init_state: bool,
     pub asserts: Vec<ConstraintAssert>,
     pub address_checks: Vec<ConstraintAddress>,
+    pub next_instruction: Option<NextInstructionContext>,
 }
 
 impl<'a> AccountGenerator<'a> {
@@ -87,6 +114,7 @@ impl<'a> AccountGenerator<'a> {
             init_state: false,
             asserts: Vec::new(),
             address_checks: Vec::new(),
+            next_instruction: None,
         }
     }
 }
@@ -107,11 +135,23 @@ fn seed_temp_bindings(
     (bindings, temps)
 }
 
+/// Generates an `Error` tagged with the account name and the constraint kind.
+fn gen_constraint_error(name: &Ident, constraint: &str, err: TokenStream) -> TokenStream {
+    let name_str = name.to_string();
+    quote!(Error::from(#err).with_account(#name_str).with_constraint(#constraint))
+}
+
 /// Generates a guard that returns an error when an address comparison fails.
+///
+/// The expected (`rhs`) and found (`lhs`) addresses are logged with `log_address_mismatch`.
 fn gen_address_guard(lhs: TokenStream, rhs: TokenStream, err: TokenStream) -> TokenStream {
     quote! {
-        if hint::unlikely(!address::address_eq(#lhs, #rhs)) {
-            return Err(#err);
+        {
+            let (found, expected) = (#lhs, #rhs);
+            if hint::unlikely(!address::address_eq(found, expected)) {
+                log_address_mismatch(expected, found);
+                return Err(#err);
+            }
         }
     }
 }
@@ -130,6 +170,7 @@ impl AccountGenerator<'_> {
                     }
                 }
                 AccountType::Mint { .. } => programs.push("TokenProgram".to_string()),
+                AccountType::Stake { .. } => programs.push("StakeProgram".to_string()),
                 _ => (),
             }
         }
@@ -157,9 +198,16 @@ impl AccountGenerator<'_> {
             .map(|p| quote!(#p))
             .unwrap_or(quote!(program_id));
 
+        let checkpoint = format!("{}:pda", self.account.name);
+        let name_str = self.account.name.to_string();
+        let constraint = ctx.constraint();
         let use_create = !matches!(mode, PdaMode::FindBump) && ctx.bump.is_some();
         let define_key = matches!(mode, PdaMode::DeriveAddress);
 
+        if define_key && ctx.is_seeded && ctx.bump.is_none() {
+            return Ok(self.get_stored_bump_pda(ctx, &program_id));
+        }
+
         if use_create {
             let Some(bump) = ctx.bump.as_ref() else {
                 error!(&self.account.name, "No bump specified for the current PDA.");
@@ -184,12 +232,18 @@ impl AccountGenerator<'_> {
                 }
             };
 
+            let create_pda = quote! {
+                Address::create_program_address(&#seeds_token, &#program_id)
+                    .map_err(Error::from)
+                    .trace_constraint(#name_str, #constraint)?
+            };
             let create_pda = if define_key {
-                quote!(let #pda_key = Address::create_program_address(&#seeds_token, &#program_id)?;)
+                quote!(let #pda_key = #create_pda;)
             } else {
-                quote!(Address::create_program_address(&#seeds_token, &#program_id)?;)
+                quote!(#create_pda;)
             };
             Ok(quote! {
+                profiling::checkpoint(#checkpoint);
                 let #pda_bump = #bump;
                 #create_pda
             })
@@ -215,10 +269,12 @@ impl AccountGenerator<'_> {
             let pda_bump = &idents.bump;
             let key_token = if define_key {
                 quote! {
+                    profiling::checkpoint(#checkpoint);
                     let (#pda_key, #pda_bump) = Address::find_program_address(&#seeds_token, &#program_id);
                 }
             } else {
                 quote! {
+                    profiling::checkpoint(#checkpoint);
                     let (_, #pda_bump) = Address::find_program_address(&#seeds_token, &#program_id);
                 }
             };
@@ -226,6 +282,64 @@ impl AccountGenerator<'_> {
         }
     }
 
+    /// Derives the address of a `seeded` account without an explicit bump, using the
+    /// bump stored in its `#[bump]` field when the state has one.
+    fn get_stored_bump_pda(&self, ctx: &PdaContext, program_id: &TokenStream) -> TokenStream {
+        let idents = AccountIdents::new(&self.account.name);
+        let (pda_key, pda_bump, state) = (&idents.key, &idents.bump, &idents.state);
+        let name = &self.account.name;
+        let inner_ty = &self.account.inner_ty;
+        let checkpoint = format!("{name}:pda");
+        let name_str = name.to_string();
+        let constraint = ctx.constraint();
+
+        let Some(ref seed_keys) = ctx.keys else {
+            return quote! {
+                profiling::checkpoint(#checkpoint);
+                const {
+                    assert!(
+                        <#inner_ty as StoredBump>::HAS_STORED_BUMP,
+                        "`seeded` without seeds needs a `#[bump]` field or a `bump = ...` constraint"
+                    )
+                };
+                let #pda_bump = #state.stored_bump().unwrap_or_default();
+                let #pda_key = Address::create_program_address(&#state.seeds().seeds_with_bump(&[#pda_bump]), &#program_id)
+                    .map_err(Error::from)
+                    .trace_constraint(#name_str, #constraint)?;
+            };
+        };
+
+        // Only accounts with a `#[bump]` field load their state to read it.
+        let state_token = if self.init_state {
+            quote!(#state)
+        } else {
+            let account_ty = self.account.get_ty();
+            quote! {
+                account_state::<#account_ty>(#name.as_ref())
+                    .map_err(Error::from)
+                    .trace_account(#name_str)?
+            }
+        };
+
+        quote! {
+            profiling::checkpoint(#checkpoint);
+            let stored_bump = if <#inner_ty as StoredBump>::HAS_STORED_BUMP {
+                #state_token.stored_bump()
+            } else {
+                None
+            };
+            let (#pda_key, #pda_bump) = match stored_bump {
+                Some(bump) => (
+                    Address::create_program_address(&#inner_ty::derive(#seed_keys).seeds_with_bump(&[bump]), &#program_id)
+                        .map_err(Error::from)
+                        .trace_constraint(#name_str, #constraint)?,
+                    bump,
+                ),
+                None => Address::find_program_address(&#inner_ty::derive(#seed_keys).as_seeds(), &#program_id),
+            };
+        }
+    }
+
     fn get_signer_init(&self, ctx: &PdaContext) -> Result<TokenStream, syn::Error> {
         let idents = AccountIdents::new(&self.account.name);
         let Some(ref punctuated_keys) = ctx.keys else {
@@ -284,7 +398,9 @@ impl AccountGenerator<'_> {
         signers: TokenStream,
     ) -> Result<TokenStream, syn::Error> {
         let name = &self.account.name;
-        if !self.account.meta.is_mutable || !self.is_init_signer() {
+        // Accounts derived with a seed are authorized by their base account.
+        let needs_signer = ctx.with_seed.is_none();
+        if !self.account.meta.is_mutable || (needs_signer && !self.is_init_signer()) {
             error!(name, "The account needs to be mutable and signer");
         }
         let Some(ref payer) = ctx.payer else {
@@ -294,6 +410,7 @@ impl AccountGenerator<'_> {
             );
         };
 
+        let name_str = name.to_string();
         let init_token = match &self.account_ty {
             AccountType::TokenAccount {
                 is_ata,
@@ -308,9 +425,9 @@ impl AccountGenerator<'_> {
                 };
 
                 if *is_ata {
-                    quote!(SplCreateToken::create_associated_token_account(#name, &#payer, &#mint, &#owner, &system_program, &token_program)?)
+                    quote!(SplCreateToken::create_associated_token_account(#name, &#payer, &#mint, &#owner, &system_program, &token_program))
                 } else {
-                    quote!(SplCreateToken::create_token_account(#name, &rent, &#payer, &#mint, &#owner, #signers)?)
+                    quote!(SplCreateToken::create_token_account(#name, &rent, &#payer, &#mint, &#owner, #signers))
                 }
             }
             AccountType::Mint {
@@ -328,17 +445,48 @@ impl AccountGenerator<'_> {
                 } else {
                     quote!(None)
                 };
-                quote!(SplCreateMint::create_mint(#name, &rent, &#payer, &#authority, #decimals, #f_auth_token, #signers)?)
+                quote!(SplCreateMint::create_mint(#name, &rent, &#payer, &#authority, #decimals, #f_auth_token, #signers))
             }
-            AccountType::Other { space, .. } => {
+            AccountType::Stake {
+                staker,
+                withdrawer,
+                rent_sysvar,
+            } => {
+                let Some(staker) = staker else {
+                    error!(name, "A `staker` needs to be specified for the `init` or `init_if_needed` constraint.");
+                };
+                let Some(withdrawer) = withdrawer else {
+                    error!(name, "A `withdrawer` needs to be specified for the `init` or `init_if_needed` constraint.");
+                };
+                let Some(rent_sysvar) = rent_sysvar else {
+                    error!(
+                        name,
+                        "One constraint requires including the `Sysvar<Rent>` account."
+                    );
+                };
+                quote!(CreateStakeAccount::create_stake_account(#name, &rent, &#payer, &#rent_sysvar, #staker.address(), #withdrawer.address(), #signers))
+            }
+            AccountType::Other {
+                space, init_value, ..
+            } => {
                 let account_ty = &self.account.inner_ty;
                 let default_space = parse_quote!(#account_ty::SPACE);
                 let space = space.as_ref().unwrap_or(&default_space);
-                quote!(CreateAccountCpi::create(#name, &rent, &#payer, &program_id, #space, #signers)?)
+                match (&ctx.with_seed, init_value) {
+                    (Some(ConstraintInitWithSeed { base, seed }), _) => {
+                        quote!(CreateAccountCpi::create_with_seed(#name, &rent, &#payer, &#base, #seed, &program_id, #space, #signers))
+                    }
+                    (None, Some(value)) => {
+                        quote!(CreateAccountCpi::create_with(#name, &rent, &#payer, &program_id, #space, #signers, &#value))
+                    }
+                    (None, None) => {
+                        quote!(CreateAccountCpi::create(#name, &rent, &#payer, &program_id, #space, #signers))
+                    }
+                }
             }
         };
 
-        Ok(init_token)
+        Ok(quote!(#init_token.trace_constraint(#name_str, "init")?))
     }
 
     fn generate_init(
@@ -355,6 +503,7 @@ impl AccountGenerator<'_> {
             quote!(None)
         };
         let init_token = self.get_init_token(init_ctx, signers)?;
+        let checkpoint = format!("{name}:init");
 
         let init_account_token = if let Some(ref pda_ctx) = self.pda {
             let mode = if init_ctx.is_init_if_needed {
@@ -364,13 +513,27 @@ impl AccountGenerator<'_> {
             };
             let pda_token = self.get_pda(pda_ctx, mode)?;
             let seeds_token = self.get_signer_init(pda_ctx)?;
+            let store_bump_token = if pda_ctx.is_seeded {
+                let inner_ty = &self.account.inner_ty;
+                let pda_bump = &AccountIdents::new(name).bump;
+                quote! {
+                    if <#inner_ty as StoredBump>::HAS_STORED_BUMP {
+                        <#inner_ty as StoredBump>::write_stored_bump(&mut #name.raw_mut_data()?, #pda_bump);
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
             quote! {
                 #pda_token
                 #seeds_token
+                profiling::checkpoint(#checkpoint);
                 let #name = { #init_token };
+                #store_bump_token
             }
         } else {
             quote! {
+                profiling::checkpoint(#checkpoint);
                 let #name: #account_ty = {
                     #init_token
                 };
@@ -379,9 +542,25 @@ impl AccountGenerator<'_> {
 
         if init_ctx.is_init_if_needed {
             let account_token = self.account_token()?;
+            // The System program only checks the derived address when creating the account.
+            let with_seed_guard = init_ctx.with_seed.as_ref().map(|ConstraintInitWithSeed { base, seed }| {
+                let name_str = name.to_string();
+                let guard = gen_address_guard(
+                    quote!(#name.address()),
+                    quote!(&with_seed_key),
+                    gen_constraint_error(name, "init::with_seed", quote!(ProgramError::InvalidSeeds)),
+                );
+                quote! {
+                    let with_seed_key = Address::create_with_seed(#base.address(), #seed, &program_id)
+                        .map_err(Error::from)
+                        .trace_constraint(#name_str, "init::with_seed")?;
+                    #guard
+                }
+            });
             Ok(quote! {
                 let #return_ty = if !#name.owned_by(&Address::default()) {
                     #account_token
+                    #with_seed_guard
                     #return_ty
                 }else {
                     #init_account_token
@@ -399,35 +578,47 @@ impl AccountGenerator<'_> {
         };
 
         let name = &self.account.name;
-        let name_str = name.to_string();
         let pda_key = &idents.key;
 
         let pda = self.get_pda(pda_ctx, PdaMode::DeriveAddress)?;
         let guard = gen_address_guard(
             quote!(#name.address()),
             quote!(&#pda_key),
-            quote!(Error::new(ProgramError::InvalidSeeds).with_account(#name_str)),
+            gen_constraint_error(
+                name,
+                pda_ctx.constraint(),
+                quote!(ProgramError::InvalidSeeds),
+            ),
         );
 
         Ok(quote! { #pda #guard })
     }
 
     fn verify_type_constraints(&self, idents: &AccountIdents) -> TokenStream {
-        let name_str = self.account.name.to_string();
+        let name = &self.account.name;
         let state = &idents.state;
 
         match self.account_ty {
             AccountType::TokenAccount {
+                is_ata,
                 ref mint,
                 ref owner,
-                ..
             } => {
+                let (mint_constraint, owner_constraint) = if is_ata {
+                    ("associated_token::mint", "associated_token::authority")
+                } else {
+                    ("token::mint", "token::owner")
+                };
                 let mut token = TokenStream::new();
                 if let Some(mint) = mint {
                     token.extend(gen_address_guard(
                         quote!(#state.mint()),
                         quote!(#mint.address()),
-                        quote!(ErrorCode::TokenConstraintViolated.into()),
+                        gen_constraint_error(
+                            name,
+                            mint_constraint,
+                            quote!(ErrorCode::TokenConstraintViolated),
+                        ),
                     ));
                 }
 
@@ -435,12 +626,47 @@ impl AccountGenerator<'_> {
                     token.extend(gen_address_guard(
                         quote!(#state.owner()),
                         quote!(#owner.address()),
-                        quote!(ErrorCode::TokenConstraintViolated.into()),
+                        gen_constraint_error(
+                            name,
+                            owner_constraint,
+                            quote!(ErrorCode::TokenConstraintViolated),
+                        ),
                     ));
                 }
                 token
             }
             AccountType::Mint { .. } => TokenStream::new(),
+            AccountType::Stake {
+                ref staker,
+                ref withdrawer,
+                ..
+            } => {
+                let authorities = [
+                    ("stake::staker", quote!(staker), staker),
+                    ("stake::withdrawer", quote!(withdrawer), withdrawer),
+                ];
+                let mut token = TokenStream::new();
+                for (constraint, getter, authority) in authorities {
+                    let Some(authority) = authority else {
+                        continue;
+                    };
+                    let error = gen_constraint_error(
+                        name,
+                        constraint,
+                        quote!(ErrorCode::StakeConstraintViolated),
+                    );
+                    token.extend(quote! {
+                        if hint::unlikely(
+                            !#state
+                                .#getter()
+                                .is_some_and(|key| address::address_eq(key, #authority.address())),
+                        ) {
+                            return Err(#error);
+                        }
+                    });
+                }
+                token
+            }
             AccountType::Other { ref targets, .. } => {
                 let basic_error: Expr = parse_quote!(ErrorCode::HasOneConstraint);
                 targets
@@ -450,7 +676,7 @@ impl AccountGenerator<'_> {
                         gen_address_guard(
                             quote!(&#state.#target),
                             quote!(#target.address()),
-                            quote!(Error::from(#error).with_account(#name_str)),
+                            gen_constraint_error(name, "has_one", quote!(#error)),
                         )
                     })
                     .collect()
@@ -464,31 +690,72 @@ impl AccountGenerator<'_> {
             .map(|ConstraintAssert { assert, error }| {
                 let basic_error: Expr = parse_quote!(ErrorCode::AssertConstraint);
                 let error = error.as_ref().unwrap_or(&basic_error);
+                let error = gen_constraint_error(&self.account.name, "assert", quote!(#error));
                 quote! {
                     if hint::unlikely(!(#assert)) {
-                        return Err(#error.into());
+                        return Err(#error);
                     }
                 }
             })
             .collect()
     }
 
+    fn verify_next_instruction(&self) -> TokenStream {
+        let Some(NextInstructionContext {
+            program: Some(ref program),
+            ref discriminator,
+        }) = self.next_instruction
+        else {
+            return TokenStream::new();
+        };
+
+        let name = &self.account.name;
+        let name_str = name.to_string();
+        let discriminator = discriminator
+            .as_ref()
+            .map_or_else(|| quote!(&[]), |discriminator| quote!(&#discriminator));
+        let error = gen_constraint_error(
+            name,
+            "next_instruction",
+            quote!(ErrorCode::InstructionConstraint),
+        );
+
+        quote! {
+            if hint::unlikely(
+                !#name
+                    .view()
+                    .map_err(Error::from)
+                    .trace_account(#name_str)?
+                    .next_instruction()
+                    .is_ok_and(|ix| ix.is_instruction(&#program, #discriminator)),
+            ) {
+                return Err(#error);
+            }
+        }
+    }
+
     pub fn account_token(&self) -> Result<TokenStream, syn::Error> {
         let name = &self.account.name;
         let name_str = name.to_string();
         let account_ty = self.account.get_ty();
         let idents = AccountIdents::new(name);
 
-        let mut token = quote!(let #name = <#account_ty as FromAccountInfo>::try_from_info(#name).trace_account(#name_str)?;);
-
-        if self.init_state {
+        let mut token = if self.init_state {
             let state = &idents.state;
-            token.extend(quote!(let #state = #name.data_unchecked()?;));
-        }
+            let info = &idents.info;
+            quote! {
+                let #info = #name;
+                let #name = <#account_ty as FromAccountInfo>::try_from_info(#info).trace_account(#name_str)?;
+                let #state = account_state::<#account_ty>(#info).map_err(Error::from).trace_account(#name_str)?;
+            }
+        } else {
+            quote!(let #name = <#account_ty as FromAccountInfo>::try_from_info(#name).trace_account(#name_str)?;)
+        };
 
         token.extend(self.verify_pda_address(&idents)?);
         token.extend(self.verify_type_constraints(&idents));
         token.extend(self.verify_assertions());
+        token.extend(self.verify_next_instruction());
 
         Ok(token)
     }
@@ -510,7 +777,8 @@ impl AccountGenerator<'_> {
             self.account_token()?
         };
 
-        let mut token = TokenStream::new();
+        let name_str = name.to_string();
+        let mut token = quote!(profiling::checkpoint(#name_str););
 
         if self.account.meta.is_optional {
             token.extend(quote! {
@@ -532,7 +800,7 @@ impl AccountGenerator<'_> {
             token.extend(gen_address_guard(
                 quote!(#name.address()),
                 quote!(#check),
-                quote!(#error.into()),
+                gen_constraint_error(name, "address", quote!(#error)),
             ));
         }
 
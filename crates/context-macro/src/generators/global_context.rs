@@ -1,6 +1,8 @@
 use {
     crate::{
-        generators::account::{AccountGenerator, AccountType, InitContext, PdaContext},
+        generators::account::{
+            AccountGenerator, AccountType, InitContext, NextInstructionContext, PdaContext,
+        },
         ParsingContext,
     },
     proc_macro2::TokenStream,
@@ -8,7 +10,10 @@ use {
     std::collections::HashSet,
     syn::{parse_quote, Ident, ItemStruct},
     typhoon_syn::{
-        constraints::{Constraint, ConstraintAssociatedToken, ConstraintMint, ConstraintToken},
+        constraints::{
+            Constraint, ConstraintAssociatedToken, ConstraintMint, ConstraintNextInstruction,
+            ConstraintStake, ConstraintToken,
+        },
         error, Argument, Arguments,
     },
 };
@@ -75,12 +80,59 @@ impl<'a> GlobalContext<'a> {
         Some((bumps_struct, bumps_var))
     }
 
+    /// Exposes the states parsed for the constraints of read-only accounts, which keep
+    /// their data borrowed until the context is dropped.
+    pub fn generate_states(&self, context: &ParsingContext) -> Option<(ItemStruct, TokenStream)> {
+        let accounts: Vec<_> = self
+            .accounts
+            .iter()
+            .filter(|acc| {
+                acc.init_state
+                    && acc.init.is_none()
+                    && !acc.account.meta.is_mutable
+                    && !acc.account.meta.is_optional
+            })
+            .map(|acc| (&acc.account.name, acc.account.get_ty()))
+            .collect();
+
+        if accounts.is_empty() {
+            return None;
+        }
+
+        let struct_name = format_ident!("{}States", context.item_struct.ident);
+        let struct_fields = accounts
+            .iter()
+            .map(|(name, ty)| quote!(pub #name: StateOf<'info, #ty>));
+        let states_struct = parse_quote! {
+            pub struct #struct_name<'info> {
+                #(#struct_fields,)*
+            }
+        };
+
+        let assign_fields = accounts.iter().map(|(name, _)| {
+            let state_ident = format_ident!("{name}_state");
+            quote!(#name: #state_ident)
+        });
+        let states_var = quote! {
+            let states = #struct_name {
+                #(#assign_fields),*
+            };
+        };
+
+        Some((states_struct, states_var))
+    }
+
     pub fn from_parsing_context(context: &'a ParsingContext) -> Result<Self, syn::Error> {
         let mut need_rent = false;
         let mut accounts: Vec<AccountGenerator<'_>> = Vec::new();
         let mut bumps = HashSet::new();
         let mut program_checks = HashSet::new();
         let mut states = HashSet::new();
+        let rent_sysvar = context
+            .accounts
+            .iter()
+            .find(|account| account.inner_ty == "Rent")
+            .map(|account| account.name.to_owned());
 
         //TODO optimize sorting etc..
         for account in &context.accounts {
@@ -95,8 +147,14 @@ impl<'a> GlobalContext<'a> {
                     decimals: None,
                     freeze_authority: Box::new(None),
                 },
+                "StakeAccount" => AccountType::Stake {
+                    staker: None,
+                    withdrawer: None,
+                    rent_sysvar: rent_sysvar.to_owned(),
+                },
                 _ => AccountType::Other {
                     space: None,
+                    init_value: None,
                     targets: vec![],
                 },
             };
@@ -142,6 +200,20 @@ impl<'a> GlobalContext<'a> {
                             )
                         }
                     }
+                    Constraint::InitValue(constraint_init_value) => {
+                        if generator.init.is_none() {
+                            error!(name, "`init_value` can only be specified with `init` or `init_if_needed` constraint.");
+                        }
+
+                        if let AccountType::Other { init_value, .. } = &mut generator.account_ty {
+                            *init_value = Some(constraint_init_value.value.to_owned())
+                        } else {
+                            error!(
+                                name,
+                                "`init_value` cannot be used on `Mint` or `TokenAccount` type."
+                            )
+                        }
+                    }
                     Constraint::Seeded(constraint_seeded) => {
                         if generator.pda.is_some() {
                             error!(name, "`seeds` or `seeded` are already defined.")
@@ -300,7 +372,7 @@ impl<'a> GlobalContext<'a> {
 
                         generator.init = Some(InitContext {
                             is_init_if_needed: true,
-                            payer: None,
+                            ..Default::default()
                         })
                     }
                     Constraint::Assert(constraint_assert) => {
@@ -315,6 +387,104 @@ impl<'a> GlobalContext<'a> {
                         }
                         generator.address_checks.push(constraint.clone());
                     }
+                    Constraint::NextInstruction(constraint) => {
+                        if account.inner_ty != "Instructions" {
+                            error!(
+                                name,
+                                "`next_instruction` can only be used with the `Sysvar<Instructions>` type."
+                            );
+                        }
+
+                        let next_instruction = generator
+                            .next_instruction
+                            .get_or_insert_with(NextInstructionContext::default);
+                        match constraint {
+                            ConstraintNextInstruction::Program(expr) => {
+                                next_instruction.program = Some(expr.to_owned())
+                            }
+                            ConstraintNextInstruction::Discriminator(expr) => {
+                                next_instruction.discriminator = Some(expr.to_owned())
+                            }
+                        }
+                    }
+                    Constraint::InitWithSeed(constraint) => {
+                        let Some(init_ctx) = &mut generator.init else {
+                            error!(
+                                name,
+                                "`init::with_seed` can only be used with `init` or `init_if_needed` constraint."
+                            );
+                        };
+                        init_ctx.with_seed = Some(constraint.clone());
+                    }
+                    Constraint::Stake(constraint_stake) => {
+                        if let AccountType::Stake {
+                            staker, withdrawer, ..
+                        } = &mut generator.account_ty
+                        {
+                            states.insert(name.to_string());
+
+                            match constraint_stake {
+                                ConstraintStake::Staker(expr) => *staker = Some(expr.to_owned()),
+                                ConstraintStake::Withdrawer(expr) => {
+                                    *withdrawer = Some(expr.to_owned())
+                                }
+                            }
+                        } else {
+                            error!(
+                                name,
+                                "`stake` constraint can only be used with the `StakeAccount` type"
+                            )
+                        }
+                    }
+                }
+            }
+
+            if generator
+                .next_instruction
+                .as_ref()
+                .is_some_and(|next_instruction| next_instruction.program.is_none())
+            {
+                error!(
+                    name,
+                    "`next_instruction::discriminator` requires `next_instruction::program`."
+                );
+            }
+
+            if let Some(with_seed) = generator
+                .init
+                .as_ref()
+                .and_then(|init_ctx| init_ctx.with_seed.as_ref())
+            {
+                // The context has no seeds to sign for a PDA base.
+                if !context
+                    .accounts
+                    .iter()
+                    .any(|account| account.name == with_seed.base && account.meta.is_signer)
+                {
+                    error!(
+                        name,
+                        "The `init::with_seed` base needs to be a `Signer` of the context."
+                    );
+                }
+
+                if generator.pda.is_some() {
+                    error!(
+                        name,
+                        "`init::with_seed` cannot be used with `seeds` or `seeded`."
+                    );
+                }
+
+                if !matches!(
+                    generator.account_ty,
+                    AccountType::Other {
+                        init_value: None,
+                        ..
+                    }
+                ) {
+                    error!(
+                        name,
+                        "`init::with_seed` can only be used with program accounts without `init_value`."
+                    );
                 }
             }
 
@@ -324,6 +494,20 @@ impl<'a> GlobalContext<'a> {
             accounts.push(generator);
         }
 
+        // `seeded` accounts without a bump expose the one they derive, and read it
+        // along with their seeds from their state when they have no keys.
+        for account in &accounts {
+            let Some(pda) = account.pda.as_ref() else {
+                continue;
+            };
+            if pda.is_seeded && pda.bump.is_none() {
+                bumps.insert(account.account.name.to_string());
+                if pda.keys.is_none() {
+                    states.insert(account.account.name.to_string());
+                }
+            }
+        }
+
         for state in states.iter() {
             for account in &mut accounts {
                 if &account.account.name.to_string() == state {
@@ -29,12 +29,13 @@ pub fn context(_attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(generator.into_token_stream())
 }
 
-type BumpsStruct = (ItemStruct, TokenStream2);
+type GeneratedStruct = (ItemStruct, TokenStream2);
 
 struct TokenGenerator {
     item_struct: ItemStruct,
     accounts_token: Vec<TokenStream2>,
-    bumps: Option<BumpsStruct>,
+    bumps: Option<GeneratedStruct>,
+    states: Option<GeneratedStruct>,
     args: Option<(Ident, Option<TokenStream2>)>,
     needs_rent: bool,
 }
@@ -55,6 +56,7 @@ impl TokenGenerator {
         }
 
         let bumps = global_context.generate_bumps(&context);
+        let states = global_context.generate_states(&context);
         let args = global_context.generate_args(&context);
 
         let accounts_token = global_context
@@ -68,6 +70,7 @@ impl TokenGenerator {
             item_struct: context.item_struct,
             accounts_token,
             bumps,
+            states,
             args,
         })
     }
@@ -111,6 +114,15 @@ impl ToTokens for TokenGenerator {
             FieldInjector::new(bumps_field).visit_item_struct_mut(account_struct);
         }
 
+        let states_ident = format_ident!("states");
+        let (states_struct, states_var) = self.states.clone().unzip();
+        if let Some(ref states) = states_struct {
+            let name = &states.ident;
+            let states_field: Field = parse_quote!(pub #states_ident: #name<'info>);
+            struct_fields.push(&states_ident);
+            FieldInjector::new(states_field).visit_item_struct_mut(account_struct);
+        }
+
         let args_ident = format_ident!("args");
         let (args_assign, args_struct) = self.args.as_ref().map(|(name, args_struct)| {
             let args_field: Field = parse_quote!(pub #args_ident: &'info #name);
@@ -122,10 +134,9 @@ impl ToTokens for TokenGenerator {
             (args_assign, args_struct)
         }).unzip();
 
-        let rent = self
-            .needs_rent
-            .then_some(quote!(let rent = <Rent as Sysvar>::get()?;));
+        let rent = self.needs_rent.then_some(quote!(let rent = Rent::get()?;));
 
+        let name_str = name.to_string();
         let impl_context = quote! {
             impl #impl_generics HandlerContext<'_, 'info, 'c> for #name #ty_generics #where_clause {
                 #[inline(always)]
@@ -134,6 +145,8 @@ impl ToTokens for TokenGenerator {
                     accounts: &mut &'info [AccountView],
                     instruction_data: &mut &'c [u8],
                 ) -> ProgramResult<Self> {
+                    profiling::checkpoint(#name_str);
+
                     let [#(#name_list,)* rem @ ..] = accounts else {
                         return Err(ProgramError::NotEnoughAccountKeys.into());
                     };
@@ -144,6 +157,7 @@ impl ToTokens for TokenGenerator {
                     #(#accounts_token)*
 
                     #bumps_var
+                    #states_var
                     *accounts = rem;
 
                     Ok(#name { #(#struct_fields),* })
@@ -156,6 +170,7 @@ impl ToTokens for TokenGenerator {
         let doc = prettyplease::unparse(
             &syn::parse2::<syn::File>(quote! {
                 #bumps_struct
+                #states_struct
                 #args_struct
 
                 #impl_context
@@ -174,6 +189,7 @@ impl ToTokens for TokenGenerator {
 
         let expanded = quote! {
             #bumps_struct
+            #states_struct
             #args_struct
 
             #account_struct
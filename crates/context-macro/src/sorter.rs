@@ -3,8 +3,9 @@ use {
     std::collections::HashMap,
     typhoon_syn::{
         constraints::{
-            ConstraintAddress, ConstraintAssert, ConstraintAssociatedToken, ConstraintBump,
-            ConstraintHasOne, ConstraintPayer, ConstraintToken,
+            Constraint, ConstraintAddress, ConstraintAssert, ConstraintAssociatedToken,
+            ConstraintBump, ConstraintHasOne, ConstraintInitWithSeed, ConstraintPayer,
+            ConstraintToken,
         },
         InstructionAccount,
     },
@@ -38,6 +39,14 @@ impl ContextVisitor for DependencyLinker {
         Ok(())
     }
 
+    fn visit_init_with_seed(
+        &mut self,
+        constraint: &ConstraintInitWithSeed,
+    ) -> Result<(), syn::Error> {
+        self.add_dependency(&constraint.base);
+        Ok(())
+    }
+
     fn visit_bump(&mut self, constraint: &ConstraintBump) -> Result<(), syn::Error> {
         if let Some(ref bump) = constraint.0 {
             for name in &bump.names {
@@ -86,11 +95,29 @@ impl ContextVisitor for DependencyLinker {
 }
 
 pub fn sort_accounts(context: &mut ParsingContext) -> Result<(), syn::Error> {
+    let rent_sysvars: Vec<String> = context
+        .accounts
+        .iter()
+        .filter(|account| account.inner_ty == "Rent")
+        .map(|account| account.name.to_string())
+        .collect();
+
     let account_dependencies = context
         .accounts
         .iter()
         .map(|account| {
-            let dependencies = DependencyLinker::extract_dependencies(account)?;
+            let mut dependencies = DependencyLinker::extract_dependencies(account)?;
+            // Stake accounts are initialized with the `Rent` sysvar account.
+            if account.inner_ty == "StakeAccount"
+                && account.constraints.0.iter().any(|constraint| {
+                    matches!(
+                        constraint,
+                        Constraint::Init(_) | Constraint::InitIfNeeded(_)
+                    )
+                })
+            {
+                dependencies.extend(rent_sysvars.iter().cloned());
+            }
             Ok((account, dependencies))
         })
         .collect::<Result<Vec<_>, syn::Error>>()?;
@@ -18,6 +18,7 @@ pub trait ContextVisitor {
             Constraint::Init(constraint) => self.visit_init(constraint),
             Constraint::Payer(constraint) => self.visit_payer(constraint),
             Constraint::Space(constraint) => self.visit_space(constraint),
+            Constraint::InitValue(constraint) => self.visit_init_value(constraint),
             Constraint::Seeded(constraint) => self.visit_seeded(constraint),
             Constraint::Seeds(constraint) => self.visit_seeds(constraint),
             Constraint::Bump(constraint) => self.visit_bump(constraint),
@@ -29,6 +30,9 @@ pub trait ContextVisitor {
             Constraint::InitIfNeeded(constraint) => self.visit_init_if_needed(constraint),
             Constraint::Assert(constraint) => self.visit_assert(constraint),
             Constraint::Address(constraint) => self.visit_address(constraint),
+            Constraint::NextInstruction(constraint) => self.visit_next_instruction(constraint),
+            Constraint::Stake(constraint) => self.visit_stake(constraint),
+            Constraint::InitWithSeed(constraint) => self.visit_init_with_seed(constraint),
         }
     }
 
@@ -44,6 +48,10 @@ pub trait ContextVisitor {
         Ok(())
     }
 
+    fn visit_init_value(&mut self, _constraint: &ConstraintInitValue) -> Result<(), syn::Error> {
+        Ok(())
+    }
+
     fn visit_seeded(&mut self, _constraint: &ConstraintSeeded) -> Result<(), syn::Error> {
         Ok(())
     }
@@ -93,4 +101,22 @@ pub trait ContextVisitor {
     fn visit_address(&mut self, _constraint: &ConstraintAddress) -> Result<(), syn::Error> {
         Ok(())
     }
+
+    fn visit_next_instruction(
+        &mut self,
+        _constraint: &ConstraintNextInstruction,
+    ) -> Result<(), syn::Error> {
+        Ok(())
+    }
+
+    fn visit_stake(&mut self, _constraint: &ConstraintStake) -> Result<(), syn::Error> {
+        Ok(())
+    }
+
+    fn visit_init_with_seed(
+        &mut self,
+        _constraint: &ConstraintInitWithSeed,
+    ) -> Result<(), syn::Error> {
+        Ok(())
+    }
 }
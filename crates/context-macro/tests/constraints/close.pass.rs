@@ -0,0 +1,54 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::{CloseAccount, ClosedAccount},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Counter {
+    pub count: u64,
+}
+
+#[context]
+pub struct Close {
+    pub destination: Mut<Signer>,
+    pub counter: Mut<Account<Counter>>,
+}
+
+#[context]
+pub struct Defund {
+    pub destination: Mut<Signer>,
+    pub revived: Mut<UncheckedAccount>,
+}
+
+pub fn close(ctx: Close) -> ProgramResult {
+    ctx.counter.close_with_sentinel(&ctx.destination)
+}
+
+pub fn defund(ctx: Defund) -> ProgramResult {
+    ctx.revived.force_defund(&ctx.destination)
+}
+
+fn main() {
+    assert_eq!(CLOSED_ACCOUNT_DISCRIMINATOR, [255; 8]);
+    assert_eq!(
+        ErrorCode::try_from(ErrorCode::AccountClosed as u32),
+        Ok(ErrorCode::AccountClosed)
+    );
+}
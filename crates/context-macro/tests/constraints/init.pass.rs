@@ -64,4 +64,26 @@ pub struct InitContext {
     pub program: Program<System>,
 }
 
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Profile {
+    #[key]
+    pub owner: Address,
+    #[bump]
+    pub bump: u8,
+}
+
+#[context]
+pub struct InitProfileContext {
+    pub owner: Mut<Signer>,
+    #[constraint(
+        init_if_needed,
+        payer = owner,
+        seeded = [owner.address()],
+        bump
+    )]
+    pub profile: Mut<Signer<Account<Profile>>>,
+    pub program: Program<System>,
+}
+
 pub fn main() {}
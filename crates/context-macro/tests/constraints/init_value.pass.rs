@@ -0,0 +1,49 @@
+extern crate alloc;
+
+use {
+    alloc::string::String,
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::CreateAccountCpi,
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(AccountState)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct Profile {
+    pub authority: Address,
+    #[max_len(32)]
+    pub name: String,
+}
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        init_value = Profile {
+            authority: *payer.address(),
+            name: String::new(),
+        },
+    )]
+    pub profile: Mut<UncheckedSigner<Account<Profile>>>,
+    pub system_program: Program<System>,
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        sysvars::{rent::Rent, Sysvar as _},
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::{LamportsChecked, ProgramLamports},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Vault {
+    pub amount: u64,
+}
+
+#[context]
+pub struct Withdraw {
+    pub payer: Mut<Signer>,
+    pub recipient: Mut<UncheckedAccount>,
+    pub vault: Mut<Account<Vault>>,
+    pub old_vault: Mut<Account<Vault>>,
+}
+
+pub fn withdraw(ctx: Withdraw) -> ProgramResult {
+    let amount = ctx.vault.data()?.amount;
+    ctx.vault.send(&ctx.recipient, amount, &Rent::get()?)?;
+    ctx.old_vault.drain(&ctx.recipient)?;
+    ctx.payer.send(&ctx.recipient, 1_000)
+}
+
+fn main() {}
@@ -0,0 +1,139 @@
+use {
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        cpi::Signer as CpiSigner,
+        error::ProgramError,
+        instruction::seeds,
+        sysvars::{clock::Clock, Sysvar},
+        AccountView,
+    },
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::LookupTableCpi,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct Create {
+    pub payer: Mut<Signer>,
+    pub authority: UncheckedAccount,
+    pub lookup_table: Mut<UncheckedAccount>,
+    pub alt_program: Program<AddressLookupTable>,
+    pub system_program: Program<System>,
+}
+
+#[context]
+pub struct Manage {
+    pub payer: Mut<Signer>,
+    pub authority: UncheckedAccount,
+    pub lookup_table: Mut<Account<LookupTable>>,
+    pub alt_program: Program<AddressLookupTable>,
+    pub system_program: Program<System>,
+}
+
+pub fn create(ctx: Create) -> ProgramResult {
+    let bump = [255];
+    let seeds = seeds!(b"authority", &bump);
+    ctx.alt_program.create_lookup_table(
+        &ctx.lookup_table,
+        &ctx.authority,
+        &ctx.payer,
+        &ctx.system_program,
+        Clock::get()?.slot,
+        254,
+        Some(&[CpiSigner::from(&seeds)]),
+    )
+}
+
+pub fn manage(ctx: Manage) -> ProgramResult {
+    let bump = [255];
+    let seeds = seeds!(b"authority", &bump);
+    let signers = [CpiSigner::from(&seeds)];
+
+    // The table data must be released before the CPIs borrow the account again.
+    let (can_extend, is_deactivated) = {
+        let table = ctx.lookup_table.data::<LookupTableData>()?;
+        (
+            table.entries.len() < LOOKUP_TABLE_MAX_ADDRESSES
+                && table.authority() == Some(ctx.authority.address()),
+            table.is_deactivated(),
+        )
+    };
+
+    if can_extend {
+        ctx.alt_program.extend_lookup_table(
+            &ctx.lookup_table,
+            &ctx.authority,
+            &ctx.payer,
+            &ctx.system_program,
+            &[*ctx.payer.address()],
+            Some(&signers),
+        )?;
+    }
+
+    if is_deactivated {
+        ctx.alt_program.close_lookup_table(
+            &ctx.lookup_table,
+            &ctx.authority,
+            &ctx.payer,
+            Some(&signers),
+        )
+    } else {
+        ctx.alt_program
+            .deactivate_lookup_table(&ctx.lookup_table, &ctx.authority, Some(&signers))?;
+        ctx.alt_program
+            .freeze_lookup_table(&ctx.lookup_table, &ctx.authority, Some(&signers))
+    }
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<LookupTable>(), 52);
+
+    // A table deactivated at slot 7 and last extended at slot 5 from its second address.
+    let authority = Address::new_from_array([9; 32]);
+    let mut data = [0u8; 56 + 2 * 32];
+    data[..4].copy_from_slice(LookupTable::DISCRIMINATOR);
+    data[4..12].copy_from_slice(&7u64.to_le_bytes());
+    data[12..20].copy_from_slice(&5u64.to_le_bytes());
+    data[20] = 1;
+    data[21] = 1;
+    data[22..54].copy_from_slice(authority.as_ref());
+    data[56..88].fill(1);
+    data[88..].fill(2);
+
+    let table =
+        <TrailingStrategy<Address> as Accessor<LookupTable>>::access(&data[4..]).unwrap();
+    assert_eq!(table.deactivation_slot(), 7);
+    assert!(table.is_deactivated());
+    assert_eq!(table.last_extended_slot(), 5);
+    assert_eq!(table.last_extended_slot_start_index(), 1);
+    assert_eq!(table.authority(), Some(&authority));
+    assert_eq!(
+        table.entries,
+        [Address::new_from_array([1; 32]), Address::new_from_array([2; 32])]
+    );
+
+    // Frozen tables have no authority.
+    data[21] = 0;
+    data[22..54].fill(0);
+    let table =
+        <TrailingStrategy<Address> as Accessor<LookupTable>>::access(&data[4..]).unwrap();
+    assert_eq!(table.authority(), None);
+
+    let (address, bump) = find_lookup_table_address(&authority, 5);
+    assert_eq!(
+        Address::create_program_address(
+            &[authority.as_ref(), &5u64.to_le_bytes(), &[bump]],
+            &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        )
+        .unwrap(),
+        address
+    );
+}
@@ -0,0 +1,47 @@
+use {
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        cpi::Signer as CpiSigner,
+        error::ProgramError,
+        instruction::seeds,
+        AccountView,
+    },
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::MemoCpi,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct Log {
+    pub authority: Signer,
+    pub vault: UncheckedAccount,
+    pub memo_program: Program<Memo>,
+}
+
+pub fn log(ctx: Log) -> ProgramResult {
+    ctx.memo_program
+        .memo("deposit", &[ctx.authority.as_ref()], None)?;
+
+    let seeds = seeds!(b"vault");
+    ctx.memo_program.memo(
+        "withdraw",
+        &[ctx.authority.as_ref(), ctx.vault.as_ref()],
+        Some(&[CpiSigner::from(&seeds)]),
+    )
+}
+
+fn main() {
+    assert_eq!(
+        MEMO_PROGRAM_ID,
+        Address::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")
+    );
+    assert!(<Memo as CheckProgramId>::address_eq(&MEMO_PROGRAM_ID));
+}
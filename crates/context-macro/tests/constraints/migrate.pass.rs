@@ -0,0 +1,63 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::MigrateAccount,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(name = "Counter")]
+pub struct CounterV1 {
+    pub count: u32,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[discriminator(layout = 2)]
+#[migrate_from(CounterV1)]
+pub struct Counter {
+    pub count: u64,
+    pub authority: Address,
+}
+
+impl MigrateFrom<CounterV1> for Counter {
+    fn migrate_from(previous: &CounterV1) -> Self {
+        Counter {
+            count: previous.count as u64,
+            authority: Address::default(),
+        }
+    }
+}
+
+#[context]
+pub struct MigrateContext {
+    pub payer: Mut<Signer>,
+    pub counter: Mut<VersionedAccount<Counter>>,
+    pub system_program: Program<System>,
+}
+
+pub fn migrate(ctx: MigrateContext) -> ProgramResult {
+    let rent = Rent::get()?;
+    let counter = ctx.counter.migrate(&ctx.payer, &rent)?;
+    counter.mut_data()?.authority = *ctx.payer.address();
+
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use {
+    pinocchio::address::{address_eq, declare_id, Address},
+    typhoon_context_macro::*,
+    typhoon_program_id_macro::program_id,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct CreateStake {
+    pub payer: Mut<Signer>,
+    pub pool: UncheckedAccount,
+    #[constraint(
+        init,
+        payer = payer,
+        stake::staker = pool,
+        stake::withdrawer = pool
+    )]
+    pub stake: Mut<Signer<Account<StakeAccount>>>,
+    pub rent_sysvar: Sysvar<Rent>,
+    pub system_program: Program<System>,
+}
+
+pub fn main() {}
@@ -0,0 +1,70 @@
+use {
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        hint, AccountView,
+    },
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility::{
+        introspection::{
+            require_ed25519_verify, require_secp256k1_verify, verified_signatures,
+            InstructionsExt, IntrospectedInstructionExt,
+        },
+        precompiles::{Ed25519SignatureOffsets, ED25519_PROGRAM_ID},
+    },
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+pub const SETTLE_DISCRIMINATOR: [u8; 1] = [2];
+
+#[context]
+pub struct Deposit {
+    #[constraint(
+        next_instruction::program = crate::ID,
+        next_instruction::discriminator = SETTLE_DISCRIMINATOR
+    )]
+    pub instructions: Sysvar<Instructions>,
+}
+
+#[context]
+pub struct Relay {
+    #[constraint(next_instruction::program = crate::ID)]
+    pub instructions: Sysvar<Instructions>,
+    pub authority: Signer,
+}
+
+pub fn deposit(ctx: Deposit) -> ProgramResult {
+    let instructions = ctx.instructions.view()?;
+    for ix in instructions.iter() {
+        let _ = ix.accounts().filter(|account| account.is_signer()).count();
+    }
+
+    Ok(())
+}
+
+pub fn relay(ctx: Relay) -> ProgramResult {
+    let instructions = ctx.instructions.view()?;
+    require_ed25519_verify(&instructions, ctx.authority.address(), b"relay")?;
+    require_secp256k1_verify(&instructions, &[0; 20], b"relay")?;
+
+    for ix in instructions
+        .iter()
+        .filter(|ix| address_eq(ix.get_program_id(), &ED25519_PROGRAM_ID))
+    {
+        for signature in verified_signatures::<Ed25519SignatureOffsets, _>(&instructions, &ix)? {
+            let (_pubkey, _message, _signature) = signature?.into_parts();
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {}
@@ -52,4 +52,61 @@ pub struct IncrementContext {
     pub counter: Mut<Account<Counter>>,
 }
 
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"vault")]
+pub struct Vault {
+    #[key]
+    pub authority: Address,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(no_prefix, constant = b"config")]
+pub struct Config {
+    pub fee: u64,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+#[context]
+pub struct VaultContext {
+    pub authority: Signer,
+    #[constraint(seeded, bump = vault.data()?.bump)]
+    pub vault: Account<Vault>,
+    #[constraint(seeded, bump = config.data()?.bump)]
+    pub config: Account<Config>,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Profile {
+    #[key]
+    pub owner: Address,
+    #[bump]
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+#[context]
+pub struct ProfileContext {
+    pub owner: Signer,
+    #[constraint(seeded)]
+    pub profile: Account<Profile>,
+    #[constraint(seeded = [owner.address()], bump)]
+    pub other_profile: Account<Profile>,
+    #[constraint(seeded = [owner.address()])]
+    pub stored_profile: Account<Profile>,
+}
+
+#[context]
+#[args(counter_id: u64)]
+pub struct FindCounterContext {
+    pub admin: Signer,
+    #[constraint(seeded = [admin.address(), &args.counter_id])]
+    pub counter: Account<Counter>,
+}
+
 pub fn main() {}
@@ -0,0 +1,38 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{self, address_eq, declare_id, Address},
+        cpi::Seed,
+        error::ProgramError,
+        hint,
+        instruction::seeds,
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Counter {
+    #[key]
+    pub admin: Address,
+    pub count: u64,
+}
+
+#[context]
+pub struct CounterContext {
+    #[constraint(seeded)]
+    pub counter: Account<Counter>,
+}
+
+pub fn main() {}
@@ -0,0 +1,144 @@
+use {
+    pinocchio::{
+        address::{self, address_eq, declare_id, Address},
+        cpi::Signer as CpiSigner,
+        error::ProgramError,
+        hint,
+        instruction::seeds,
+        sysvars::{clock::Clock, rent::Rent, Sysvar as _},
+        AccountView,
+    },
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::{CreateStakeAccount, StakeCpi},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct CreateStake {
+    pub payer: Mut<Signer>,
+    pub pool: UncheckedAccount,
+    #[constraint(
+        init,
+        payer = payer,
+        stake::staker = pool,
+        stake::withdrawer = pool
+    )]
+    pub stake: Mut<Signer<Account<StakeAccount>>>,
+    pub rent_sysvar: Sysvar<Rent>,
+    pub stake_program: Program<StakeProgram>,
+    pub system_program: Program<System>,
+}
+
+#[context]
+pub struct Manage {
+    pub pool: UncheckedAccount,
+    #[constraint(
+        stake::staker = pool,
+        stake::withdrawer = pool
+    )]
+    pub stake: Mut<Account<StakeAccount>>,
+    pub source: Mut<Account<StakeAccount>>,
+    pub vote: UncheckedAccount,
+    pub to: Mut<UncheckedAccount>,
+    pub clock: Sysvar<Clock>,
+    pub stake_history: Sysvar<StakeHistory>,
+    pub stake_config: UncheckedAccount,
+    pub stake_program: Program<StakeProgram>,
+}
+
+pub fn manage(ctx: Manage) -> ProgramResult {
+    let bump = [255];
+    let seeds = seeds!(b"pool", &bump);
+    let signers = [CpiSigner::from(&seeds)];
+
+    let state = ctx.stake.data()?.state();
+    match state {
+        Some(StakeState::Initialized) => ctx.stake.delegate(
+            &ctx.vote,
+            &ctx.clock,
+            &ctx.stake_history,
+            &ctx.stake_config,
+            &ctx.pool,
+            Some(&signers),
+        ),
+        Some(StakeState::Stake) if ctx.stake.data()?.deactivation_epoch() == Some(u64::MAX) => {
+            ctx.stake.merge(
+                &ctx.source,
+                &ctx.clock,
+                &ctx.stake_history,
+                &ctx.pool,
+                Some(&signers),
+            )?;
+            ctx.stake.split(&ctx.to, &ctx.pool, 1_000_000_000, Some(&signers))?;
+            ctx.stake.deactivate(&ctx.clock, &ctx.pool, Some(&signers))
+        }
+        _ => ctx.stake.withdraw(
+            &ctx.to,
+            &ctx.clock,
+            &ctx.stake_history,
+            &ctx.pool,
+            ctx.stake.lamports(),
+            Some(&signers),
+        ),
+    }
+}
+
+fn main() {
+    assert_eq!(StakeAccount::LEN, 200);
+
+    // A stake account delegated to `voter` at epoch 3.
+    let staker = Address::new_from_array([1; 32]);
+    let withdrawer = Address::new_from_array([2; 32]);
+    let custodian = Address::new_from_array([3; 32]);
+    let voter = Address::new_from_array([4; 32]);
+    let mut data = [0u8; 200];
+    data[..4].copy_from_slice(&2u32.to_le_bytes());
+    data[4..12].copy_from_slice(&2_282_880u64.to_le_bytes());
+    data[12..44].copy_from_slice(staker.as_ref());
+    data[44..76].copy_from_slice(withdrawer.as_ref());
+    data[76..84].copy_from_slice(&(-1i64).to_le_bytes());
+    data[84..92].copy_from_slice(&5u64.to_le_bytes());
+    data[92..124].copy_from_slice(custodian.as_ref());
+    data[124..156].copy_from_slice(voter.as_ref());
+    data[156..164].copy_from_slice(&1_000u64.to_le_bytes());
+    data[164..172].copy_from_slice(&3u64.to_le_bytes());
+    data[172..180].copy_from_slice(&u64::MAX.to_le_bytes());
+    data[188..196].copy_from_slice(&42u64.to_le_bytes());
+
+    let stake = <BytemuckStrategy as Accessor<StakeAccount>>::access(&data).unwrap();
+    assert_eq!(stake.state(), Some(StakeState::Stake));
+    assert_eq!(stake.rent_exempt_reserve(), Some(2_282_880));
+    assert_eq!(stake.staker(), Some(&staker));
+    assert_eq!(stake.withdrawer(), Some(&withdrawer));
+    let lockup = stake.lockup().unwrap();
+    assert_eq!(lockup.unix_timestamp, -1);
+    assert_eq!(lockup.epoch, 5);
+    assert_eq!(lockup.custodian, &custodian);
+    assert_eq!(stake.voter(), Some(&voter));
+    assert_eq!(stake.delegated_stake(), Some(1_000));
+    assert_eq!(stake.activation_epoch(), Some(3));
+    assert_eq!(stake.deactivation_epoch(), Some(u64::MAX));
+    assert_eq!(stake.credits_observed(), Some(42));
+
+    // Initialized accounts only hold the meta.
+    data[..4].copy_from_slice(&1u32.to_le_bytes());
+    let stake = <BytemuckStrategy as Accessor<StakeAccount>>::access(&data).unwrap();
+    assert_eq!(stake.state(), Some(StakeState::Initialized));
+    assert_eq!(stake.withdrawer(), Some(&withdrawer));
+    assert_eq!(stake.voter(), None);
+    assert_eq!(stake.delegated_stake(), None);
+
+    data[..4].copy_from_slice(&0u32.to_le_bytes());
+    let stake = <BytemuckStrategy as Accessor<StakeAccount>>::access(&data).unwrap();
+    assert_eq!(stake.state(), Some(StakeState::Uninitialized));
+    assert_eq!(stake.staker(), None);
+    assert!(<StakeAccount as CheckOwner>::owned_by(&STAKE_PROGRAM_ID));
+}
@@ -0,0 +1,73 @@
+extern crate alloc;
+
+use {
+    alloc::string::String,
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{self, address_eq, declare_id, Address},
+        error::ProgramError,
+        hint, AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_token::{TokenAccount, TokenProgram},
+    typhoon_traits::*,
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Config {
+    pub authority: Address,
+    pub fee: u64,
+}
+
+#[derive(AccountState)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct Profile {
+    pub authority: Address,
+    #[max_len(32)]
+    pub name: String,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Vault {
+    pub authority: Address,
+    pub balance: u64,
+}
+
+#[context]
+pub struct Deposit {
+    pub authority: Signer,
+    #[constraint(has_one = authority)]
+    pub config: Account<Config>,
+    #[constraint(has_one = authority)]
+    pub profile: Account<Profile>,
+    #[constraint(token::owner = authority)]
+    pub token_account: Account<TokenAccount>,
+    #[constraint(has_one = authority)]
+    pub vault: Mut<Account<Vault>>,
+    pub token_program: Program<TokenProgram>,
+}
+
+pub fn deposit(ctx: Deposit) -> ProgramResult {
+    let fee = ctx.states.config.fee;
+    let name: &String = &ctx.states.profile.name;
+    let amount = ctx.states.token_account.amount();
+
+    let mut vault = ctx.vault.mut_data()?;
+    vault.balance += amount - fee + name.len() as u64;
+
+    Ok(())
+}
+
+fn main() {}
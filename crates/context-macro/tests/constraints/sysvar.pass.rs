@@ -0,0 +1,64 @@
+use {
+    pinocchio::{
+        address::{address_eq, declare_id, Address},
+        error::ProgramError,
+        sysvars::{
+            clock::{Clock, CLOCK_ID},
+            instructions::INSTRUCTIONS_ID,
+            rent::{Rent, RENT_ID},
+            slot_hashes::SLOTHASHES_ID,
+        },
+        AccountView,
+    },
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct Introspect {
+    pub clock: Sysvar<Clock>,
+    pub rent: Sysvar<Rent>,
+    pub instructions: Sysvar<Instructions>,
+    pub slot_hashes: Sysvar<SlotHashes>,
+    pub epoch_schedule: Sysvar<EpochSchedule>,
+    pub stake_history: Sysvar<StakeHistory>,
+    pub recent_blockhashes: Sysvar<RecentBlockhashes>,
+}
+
+pub fn introspect(ctx: Introspect) -> ProgramResult {
+    let slot = ctx.clock.view()?.slot;
+    let _ = ctx.rent.view()?.try_minimum_balance(0)?;
+    let _ = ctx.instructions.view()?.load_current_index();
+    let _ = ctx.slot_hashes.view()?.get_hash(slot);
+    let _ = ctx.epoch_schedule.view()?.slots_per_epoch();
+    let _ = ctx
+        .stake_history
+        .view()?
+        .get_epoch(0)
+        .map(|entry| entry.effective());
+    let _ = ctx
+        .recent_blockhashes
+        .view()?
+        .get(0)
+        .map(|entry| entry.lamports_per_signature());
+
+    Ok(())
+}
+
+fn main() {
+    assert_eq!(<Clock as SysvarAccount>::ID, CLOCK_ID);
+    assert_eq!(<Rent as SysvarAccount>::ID, RENT_ID);
+    assert_eq!(<Instructions as SysvarAccount>::ID, INSTRUCTIONS_ID);
+    assert_eq!(<SlotHashes as SysvarAccount>::ID, SLOTHASHES_ID);
+    assert_eq!(core::mem::size_of::<EpochSchedule>(), 33);
+    assert_eq!(core::mem::size_of::<StakeHistoryEntry>(), 32);
+    assert_eq!(core::mem::size_of::<RecentBlockhashesEntry>(), 40);
+}
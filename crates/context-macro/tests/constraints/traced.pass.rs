@@ -0,0 +1,99 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        account::RuntimeAccount,
+        address::{self, address_eq, declare_id, Address},
+        error::ProgramError,
+        hint, AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+pub const ADMIN: Address = Address::new_from_array([3; 32]);
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Config {
+    pub authority: Address,
+    pub fee: u64,
+}
+
+#[context]
+pub struct Update {
+    pub authority: Signer,
+    #[constraint(has_one = authority)]
+    pub config: Account<Config>,
+}
+
+#[context]
+pub struct Admin {
+    #[constraint(address = &ADMIN)]
+    pub admin: Signer,
+}
+
+/// A runtime account followed by its data, as laid out by the loader.
+#[repr(C)]
+struct Raw<const N: usize> {
+    account: RuntimeAccount,
+    data: [u8; N],
+}
+
+impl<const N: usize> Raw<N> {
+    fn new(address: Address, owner: Address, is_signer: bool, data: [u8; N]) -> Self {
+        Raw {
+            account: RuntimeAccount {
+                borrow_state: pinocchio::account::NOT_BORROWED,
+                is_signer: is_signer as u8,
+                address,
+                owner,
+                data_len: N as u64,
+                ..RuntimeAccount::default()
+            },
+            data,
+        }
+    }
+
+    fn view(&mut self) -> AccountView {
+        unsafe { AccountView::new_unchecked(&mut self.account) }
+    }
+}
+
+fn main() {
+    let authority = Address::new_from_array([1; 32]);
+    let mut data = [0; 48];
+    data[..8].copy_from_slice(Config::DISCRIMINATOR);
+    data[8..40].copy_from_slice(&[2; 32]);
+
+    let mut signer = Raw::new(authority, Address::default(), true, []);
+    let mut config = Raw::new(Address::new_from_array([4; 32]), ID, false, data);
+    let accounts = [signer.view(), config.view()];
+
+    let err = match Update::from_entrypoint(&ID, &mut &accounts[..], &mut &[][..]) {
+        Ok(_) => panic!("the `has_one` constraint must fail"),
+        Err(err) => err,
+    };
+    assert_eq!(err.account_name(), Some("config"));
+    assert_eq!(err.constraint(), Some("has_one"));
+    assert_eq!(
+        ProgramError::from(err),
+        ProgramError::from(ErrorCode::HasOneConstraint)
+    );
+
+    let accounts = [signer.view()];
+    let err = match Admin::from_entrypoint(&ID, &mut &accounts[..], &mut &[][..]) {
+        Ok(_) => panic!("the `address` constraint must fail"),
+        Err(err) => err,
+    };
+    assert_eq!(err.account_name(), Some("admin"));
+    assert_eq!(err.constraint(), Some("address"));
+}
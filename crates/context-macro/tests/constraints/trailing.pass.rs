@@ -0,0 +1,77 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{self, address_eq, declare_id, Address},
+        error::ProgramError,
+        hint,
+        sysvars::{rent::Rent, Sysvar},
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::TrailingEntries,
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, Copy, Clone)]
+#[repr(C)]
+pub struct Order {
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[trailing(Order)]
+pub struct OrderBook {
+    pub authority: Address,
+    pub sequence: u64,
+}
+
+#[context]
+pub struct PlaceOrder {
+    pub authority: Mut<Signer>,
+    #[constraint(has_one = authority)]
+    pub book: Mut<Account<OrderBook>>,
+    pub system_program: Program<System>,
+}
+
+pub fn place_order(ctx: PlaceOrder) -> ProgramResult {
+    let rent = Rent::get()?;
+    ctx.book.push(
+        Order {
+            price: 10,
+            quantity: 1,
+        },
+        &ctx.authority,
+        &rent,
+    )?;
+
+    let mut book = ctx.book.mut_data()?;
+    book.sequence += 1;
+    book.entries.sort_unstable_by_key(|order| order.price);
+
+    Ok(())
+}
+
+pub fn cancel_order(ctx: PlaceOrder) -> ProgramResult {
+    let book = ctx.book.data()?;
+    let index = book.entries.iter().position(|order| order.price == 10);
+    drop(book);
+
+    if let Some(index) = index {
+        ctx.book.remove(index)?;
+    }
+
+    Ok(())
+}
+
+fn main() {}
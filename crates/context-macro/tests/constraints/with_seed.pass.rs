@@ -0,0 +1,113 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::{
+        address::{self, address_eq, declare_id, Address},
+        cpi::Signer as CpiSigner,
+        error::ProgramError,
+        hint,
+        instruction::seeds,
+        sysvars::{rent::Rent, Sysvar as _},
+        AccountView,
+    },
+    typhoon_account_macro::*,
+    typhoon_accounts::*,
+    typhoon_context::*,
+    typhoon_context_macro::*,
+    typhoon_errors::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+    typhoon_utility_traits::{CreateAccountCpi, NonceCpi, SystemWithSeedCpi, NONCE_ACCOUNT_LEN},
+};
+
+pub type ProgramResult<T = ()> = Result<T, Error>;
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Vault {
+    pub amount: u64,
+}
+
+#[context]
+pub struct CreateVault {
+    pub payer: Mut<Signer>,
+    pub base: Signer,
+    #[constraint(
+        init,
+        init::with_seed = (base, "vault"),
+        payer = payer
+    )]
+    pub vault: Mut<Account<Vault>>,
+    pub system_program: Program<System>,
+}
+
+#[context]
+pub struct InitVaultIfNeeded {
+    pub payer: Mut<Signer>,
+    pub base: Signer,
+    #[constraint(
+        init_if_needed,
+        init::with_seed = (base, "vault"),
+        payer = payer,
+        space = Vault::SPACE
+    )]
+    pub vault: Mut<Account<Vault>>,
+    pub system_program: Program<System>,
+}
+
+#[context]
+pub struct Nonce {
+    pub authority: UncheckedAccount,
+    pub funds: Mut<SystemAccount>,
+    pub nonce: Mut<SystemAccount>,
+    pub recipient: Mut<UncheckedAccount>,
+    pub recent_blockhashes: Sysvar<RecentBlockhashes>,
+    pub rent_sysvar: Sysvar<Rent>,
+    pub system_program: Program<System>,
+}
+
+pub fn nonce(ctx: Nonce) -> ProgramResult {
+    let bump = [255];
+    let seeds = seeds!(b"authority", &bump);
+    let signers = [CpiSigner::from(&seeds)];
+
+    ctx.funds.transfer_with_seed(
+        &ctx.authority,
+        "funds",
+        &Address::default(),
+        &ctx.recipient,
+        1_000,
+        Some(&signers),
+    )?;
+
+    if ctx.nonce.as_ref().data_len() == 0 {
+        ctx.nonce.allocate_with_seed(
+            &ctx.authority,
+            "nonce",
+            NONCE_ACCOUNT_LEN as u64,
+            &Address::default(),
+            Some(&signers),
+        )?;
+        return ctx.nonce.initialize_nonce(
+            &ctx.recent_blockhashes,
+            &ctx.rent_sysvar,
+            ctx.authority.address(),
+        );
+    }
+
+    ctx.nonce
+        .advance_nonce(&ctx.recent_blockhashes, &ctx.authority, Some(&signers))?;
+    ctx.nonce.withdraw_nonce(
+        &ctx.recipient,
+        &ctx.recent_blockhashes,
+        &ctx.rent_sysvar,
+        &ctx.authority,
+        1_000,
+        Some(&signers),
+    )?;
+    ctx.nonce
+        .authorize_nonce(&ctx.authority, ctx.recipient.address(), Some(&signers))
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use {
+    bytemuck::{AnyBitPattern, NoUninit},
+    pinocchio::address::{address_eq, declare_id, Address},
+    typhoon_account_macro::*,
+    typhoon_context_macro::*,
+    typhoon_program_id_macro::program_id,
+    typhoon_traits::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+pub struct Vault {
+    pub amount: u64,
+}
+
+#[context]
+pub struct CreateVault {
+    pub payer: Mut<Signer>,
+    pub base: UncheckedAccount,
+    #[constraint(
+        init_if_needed,
+        init::with_seed = (base, "vault"),
+        payer = payer,
+        space = Vault::SPACE
+    )]
+    pub vault: Mut<Account<Vault>>,
+    pub system_program: Program<System>,
+}
+
+pub fn main() {}
@@ -3,6 +3,7 @@
 mod arg;
 mod array;
 mod iterator;
+pub mod profiling;
 mod program_id;
 mod remaining_accounts;
 
@@ -74,6 +75,7 @@ macro_rules! impl_handler {
                     $(
                         let [<$t:lower>] = $t::from_entrypoint(program_id, accounts, instruction_data)?;
                     )*
+                    profiling::checkpoint("handler");
                     (self)($( [<$t:lower>], )*)
                 }
             }
@@ -98,7 +100,10 @@ pub fn handle<'a, 'b, 'c, T, H>(
 where
     H: Handler<'a, 'b, 'c, T>,
 {
-    match handler.call(program_id, &mut accounts, &mut instruction_data) {
+    let result = handler.call(program_id, &mut accounts, &mut instruction_data);
+    profiling::checkpoint("end");
+
+    match result {
         Ok(res) => {
             if core::mem::size_of::<H::Output>() > 0 {
                 set_return_data(bytemuck::bytes_of(&res));
@@ -0,0 +1,130 @@
+//! Compute unit checkpoints emitted by `#[context]` and [`handle`](crate::handle).
+//!
+//! With the `profiling` feature, every [`checkpoint`] logs its label followed by
+//! `sol_log_compute_units`. Without it, checkpoints compile to nothing. The
+//! [`sections`] parser turns the resulting transaction logs back into the compute
+//! units spent between consecutive checkpoints.
+
+/// Prefix of the log line written before each compute units log.
+pub const CHECKPOINT_PREFIX: &str = "Program log: typhoon-cu: ";
+
+const CONSUMPTION_PREFIX: &str = "Program consumption: ";
+
+/// Logs `label` followed by the remaining compute units.
+#[inline(always)]
+pub fn checkpoint(label: &str) {
+    #[cfg(feature = "profiling")]
+    {
+        let mut logger = solana_program_log::Logger::<128>::default();
+        logger.append("typhoon-cu: ").append(label).log();
+        solana_program_log::log_compute_units();
+    }
+
+    #[cfg(not(feature = "profiling"))]
+    let _ = label;
+}
+
+/// A checkpoint read back from the transaction logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint<'a> {
+    pub label: &'a str,
+    pub remaining: u64,
+}
+
+/// Compute units consumed from one checkpoint to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub label: &'a str,
+    pub units: u64,
+}
+
+/// Extracts the checkpoints from transaction logs, skipping every other line.
+pub fn checkpoints<'a, I, S>(logs: I) -> impl Iterator<Item = Checkpoint<'a>>
+where
+    I: IntoIterator<Item = &'a S>,
+    S: AsRef<str> + ?Sized + 'a,
+{
+    let mut label = None;
+    logs.into_iter().filter_map(move |line| {
+        let line = line.as_ref();
+        if let Some(new_label) = line.strip_prefix(CHECKPOINT_PREFIX) {
+            label = Some(new_label);
+            return None;
+        }
+
+        let remaining = line
+            .strip_prefix(CONSUMPTION_PREFIX)
+            .and_then(|rest| rest.strip_suffix(" units remaining"))
+            .and_then(|units| units.parse().ok());
+
+        match (label.take(), remaining) {
+            (Some(label), Some(remaining)) => Some(Checkpoint { label, remaining }),
+            _ => None,
+        }
+    })
+}
+
+/// Computes the units spent after each checkpoint until the following one.
+///
+/// The last checkpoint of the logs has no following one and is not yielded.
+pub fn sections<'a, I, S>(logs: I) -> impl Iterator<Item = Section<'a>>
+where
+    I: IntoIterator<Item = &'a S>,
+    S: AsRef<str> + ?Sized + 'a,
+{
+    let mut checkpoints = checkpoints(logs).peekable();
+    core::iter::from_fn(move || {
+        let current = checkpoints.next()?;
+        let next = checkpoints.peek()?;
+        Some(Section {
+            label: current.label,
+            units: current.remaining.saturating_sub(next.remaining),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let logs = [
+            "Program E64FVeubGC4NPNF2UBJYX4AkrVowf74fRJD9q6YhwstN invoke [1]",
+            "Program log: typhoon-cu: counter",
+            "Program consumption: 199000 units remaining",
+            "Program log: typhoon-cu: counter:init",
+            "Program consumption: 198500 units remaining",
+            "Program 11111111111111111111111111111111 invoke [2]",
+            "Program consumption: 197000 units remaining",
+            "Program log: typhoon-cu: handler",
+            "Program consumption: 196000 units remaining",
+            "Program log: typhoon-cu: end",
+            "Program consumption: 195900 units remaining",
+        ];
+
+        let mut sections = sections(&logs);
+        assert_eq!(
+            sections.next(),
+            Some(Section {
+                label: "counter",
+                units: 500
+            })
+        );
+        assert_eq!(
+            sections.next(),
+            Some(Section {
+                label: "counter:init",
+                units: 2500
+            })
+        );
+        assert_eq!(
+            sections.next(),
+            Some(Section {
+                label: "handler",
+                units: 100
+            })
+        );
+        assert_eq!(sections.next(), None);
+    }
+}
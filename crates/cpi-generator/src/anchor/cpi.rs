@@ -1,6 +1,6 @@
 use {
     crate::{
-        anchor::{gen_accounts, gen_instructions, program_id::gen_program_id},
+        anchor::{gen_accounts, gen_errors, gen_instructions, program_id::gen_program_id},
         idl::Idl,
     },
     quote::{format_ident, quote},
@@ -21,6 +21,7 @@ pub fn gen_cpi(idl: &Idl) -> proc_macro2::TokenStream {
     let program_id = gen_program_id(name, address);
     let accounts = gen_accounts(&idl.accounts, &idl.types);
     let instructions = gen_instructions(&idl.instructions);
+    let errors = gen_errors(name, &idl.errors);
 
     quote! {
         pub mod #mod_name {
@@ -29,6 +30,7 @@ pub fn gen_cpi(idl: &Idl) -> proc_macro2::TokenStream {
             #program_id
             #accounts
             #instructions
+            #errors
         }
     }
 }
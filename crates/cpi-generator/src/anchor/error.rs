@@ -0,0 +1,69 @@
+use {
+    crate::idl::ErrorCode,
+    heck::ToUpperCamelCase,
+    quote::{format_ident, quote},
+};
+
+pub fn gen_errors(name: &str, errors: &[ErrorCode]) -> proc_macro2::TokenStream {
+    if errors.is_empty() {
+        return quote!();
+    }
+
+    let ident = format_ident!("{}Error", name.to_upper_camel_case());
+    let variants = errors.iter().map(|error| {
+        let variant = format_ident!("{}", error.name.to_upper_camel_case());
+        let code = error.code;
+        let msg = error.msg.as_deref().unwrap_or(&error.name);
+
+        quote! {
+            #[msg(#msg)]
+            #variant = #code
+        }
+    });
+
+    quote! {
+        /// Custom errors returned by the program.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, TyphoonError)]
+        #[repr(u32)]
+        pub enum #ident {
+            #(#variants),*
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_errors() {
+        let errors = vec![
+            ErrorCode {
+                code: 6000,
+                name: "powerOff".to_string(),
+                msg: Some("The power is off".to_string()),
+            },
+            ErrorCode {
+                code: 6001,
+                name: "InvalidName".to_string(),
+                msg: None,
+            },
+        ];
+        let generated = gen_errors("lever", &errors).to_string();
+        let expected = quote! {
+            /// Custom errors returned by the program.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, TyphoonError)]
+            #[repr(u32)]
+            pub enum LeverError {
+                #[msg("The power is off")]
+                PowerOff = 6000u32,
+                #[msg("InvalidName")]
+                InvalidName = 6001u32
+            }
+        }
+        .to_string();
+
+        assert_eq!(generated, expected);
+        assert!(gen_errors("lever", &[]).is_empty());
+    }
+}
@@ -1,25 +1,28 @@
 use {
     crate::{
-        anchor::{gen_docs, gen_type_ref},
-        idl::{Field, Instruction, InstructionAccountItem},
+        anchor::{gen_docs, gen_type, gen_type_ref},
+        idl::{Field, Instruction, InstructionAccountItem, Type as IdlType},
     },
     heck::ToUpperCamelCase,
     proc_macro2::{Span, TokenStream},
     quote::{format_ident, quote},
-    syn::{Expr, Ident},
+    syn::Ident,
 };
 
+/// Upper bound of the instruction data when some argument has a variable size.
+const MAX_INSTRUCTION_DATA_LEN: usize = 1232;
+
 pub fn gen_instructions(ixs: &[Instruction]) -> TokenStream {
     let instructions = ixs.iter().map(|instruction| {
         let ident = format_ident!("{}", instruction.name.to_upper_camel_case());
-        let (metas, accounts) = gen_account_instruction(&instruction.accounts);
+        let (account_pushes, accounts) = gen_account_instruction(&instruction.accounts);
         let docs = gen_docs(&instruction.docs);
 
-        let account_metas = gen_account_metas(&metas);
         let discriminator = &instruction.discriminator.value();
-        let (arg_fields, instruction_data) =
+        let (arg_fields, data_len, instruction_data) =
             gen_instruction_data(&instruction.args, discriminator);
-        let len = metas.len();
+        let len = accounts.len();
+        let return_methods = gen_return_methods(instruction.returns.as_ref());
 
         quote! {
             /// Used for Cross-Program Invocation (CPI) calls.
@@ -29,54 +32,66 @@ pub fn gen_instructions(ixs: &[Instruction]) -> TokenStream {
                 #(#arg_fields)*
             }
 
-            impl #ident<'_> {
+            impl<'a> #ident<'a> {
                 #[inline(always)]
                 pub fn invoke(&self) -> ProgramResult {
                     self.invoke_signed(&[])
                 }
 
                 pub fn invoke_signed(&self, seeds: &[CpiSigner]) -> ProgramResult {
-                    #account_metas
+                    let mut data = [bytes::UNINIT_BYTE; #data_len];
+                    let mut metas = [bytes::UNINIT_INS_ACC; #len];
+                    let mut infos = [bytes::UNINIT_ACC_VIEW; #len];
+                    let mut builder = bytes::CpiBuilder::new(&mut data, &mut metas, &mut infos);
                     #instruction_data
+                    #(#account_pushes)*
 
-                    cpi::invoke_signed(
-                        &instruction,
-                        &[#(self.#accounts),*],
-                        seeds
-                    ).map_err(Into::into)
+                    builder.invoke_signed::<#len>(&PROGRAM_ID, seeds)
                 }
 
+                #return_methods
+
+                /// Invokes the instruction, converting the callee's custom errors with `map`.
+                ///
+                /// Returned errors are tagged with the callee program name for `log_error`.
                 #[inline(always)]
-                pub fn invoke_with_remaining<'a>(&self, remaining: impl ExactSizeIterator<Item = &'a AccountView>) -> ProgramResult {
-                    self.invoke_signed_with_remaining(&[], remaining)
+                pub fn invoke_mapped<C, E>(&self, map: impl FnOnce(C) -> E) -> ProgramResult
+                where
+                    C: TryFrom<u32>,
+                    E: Into<Error>,
+                {
+                    self.invoke_signed_mapped(&[], map)
                 }
 
-                pub fn invoke_signed_with_remaining<'a>(&self, seeds: &[CpiSigner], remaining: impl ExactSizeIterator<Item = &'a AccountView>) -> ProgramResult {
-                    let accounts_len: usize = core::cmp::min(remaining.len() + #len, 64);
-                    let mut account_infos = [bytes::UNINIT_ACC_VIEW; 64];
-                    let mut account_metas = [bytes::UNINIT_INS_ACC; 64];
-
-                    for (d, s) in account_metas[..#len].iter_mut().zip([#(#metas),*]) {
-                        d.write(s);
-                    }
-
-                    for (d, s) in account_infos[..#len].iter_mut().zip([#(self.#accounts),*]) {
-                        d.write(s);
-                    }
+                #[inline(always)]
+                pub fn invoke_signed_mapped<C, E>(&self, seeds: &[CpiSigner], map: impl FnOnce(C) -> E) -> ProgramResult
+                where
+                    C: TryFrom<u32>,
+                    E: Into<Error>,
+                {
+                    self.invoke_signed(seeds)
+                        .map_custom_err(map)
+                        .trace_program(PROGRAM_NAME)
+                }
 
-                    for (i, account) in remaining.enumerate() {
-                        account_metas[#len + i].write(instruction::InstructionAccount::new(account.address(), account.is_writable(), account.is_signer()));
-                        account_infos[#len + i].write(account);
-                    }
+                /// Invokes the instruction with `remaining` appended to its accounts.
+                ///
+                /// Fails with `ErrorCode::BufferFull` when there are more than `MAX_ACCOUNTS` accounts.
+                #[inline(always)]
+                pub fn invoke_with_remaining<const MAX_ACCOUNTS: usize>(&self, remaining: impl IntoIterator<Item = &'a AccountView>) -> ProgramResult {
+                    self.invoke_signed_with_remaining::<MAX_ACCOUNTS>(&[], remaining)
+                }
 
-                    let account_metas =  unsafe { core::slice::from_raw_parts(account_metas.as_ptr() as _, accounts_len) };
+                pub fn invoke_signed_with_remaining<const MAX_ACCOUNTS: usize>(&self, seeds: &[CpiSigner], remaining: impl IntoIterator<Item = &'a AccountView>) -> ProgramResult {
+                    let mut data = [bytes::UNINIT_BYTE; #data_len];
+                    let mut metas = [bytes::UNINIT_INS_ACC; MAX_ACCOUNTS];
+                    let mut infos = [bytes::UNINIT_ACC_VIEW; MAX_ACCOUNTS];
+                    let mut builder = bytes::CpiBuilder::new(&mut data, &mut metas, &mut infos);
                     #instruction_data
+                    #(#account_pushes)*
+                    builder.accounts(remaining)?;
 
-                    cpi::invoke_signed_with_slice(
-                        &instruction,
-                        unsafe { core::slice::from_raw_parts(account_infos.as_ptr() as _, accounts_len) },
-                        seeds
-                    ).map_err(Into::into)
+                    builder.invoke_signed::<MAX_ACCOUNTS>(&PROGRAM_ID, seeds)
                 }
             }
         }
@@ -87,65 +102,92 @@ pub fn gen_instructions(ixs: &[Instruction]) -> TokenStream {
     }
 }
 
-fn gen_instruction_data(args: &[Field], discriminator: &[u8]) -> (Vec<TokenStream>, TokenStream) {
-    let discriminator_len = discriminator.len();
-    let discriminator_expr: Expr = syn::parse_quote!([#(#discriminator),*]);
+/// Borsh size of the argument types that do not depend on their value.
+fn fixed_size(ty: &IdlType) -> Option<usize> {
+    match ty {
+        IdlType::Bool | IdlType::U8 | IdlType::I8 => Some(1),
+        IdlType::U16 | IdlType::I16 => Some(2),
+        IdlType::U32 | IdlType::I32 | IdlType::F32 => Some(4),
+        IdlType::U64 | IdlType::I64 | IdlType::F64 => Some(8),
+        IdlType::U128 | IdlType::I128 => Some(16),
+        IdlType::Pubkey => Some(32),
+        _ => None,
+    }
+}
+
+fn gen_instruction_data(
+    args: &[Field],
+    discriminator: &[u8],
+) -> (Vec<TokenStream>, usize, TokenStream) {
     let mut arg_fields = Vec::with_capacity(args.len());
     let mut arg_sizes = Vec::with_capacity(args.len());
     let mut arg_ser = Vec::with_capacity(args.len());
+    let mut data_len = Some(discriminator.len());
     for arg in args {
         let ident = Ident::new(&arg.name, Span::call_site());
         let ty_ref = gen_type_ref(&arg.ty);
         arg_fields.push(quote!(pub #ident: #ty_ref,));
         arg_sizes.push(quote!(BorshStrategy::<false>::size(&self.#ident)?));
         arg_ser.push(quote!(BorshStrategy::<false>::write_into(&mut writer, &self.#ident)?;));
+        data_len = data_len
+            .zip(fixed_size(&arg.ty))
+            .map(|(len, size)| len + size);
     }
 
     let instruction_data = if arg_ser.is_empty() {
         quote! {
-            let instruction_data = #discriminator_expr;
-
-            let instruction = instruction::InstructionView {
-                program_id: &PROGRAM_ID,
-                accounts: &account_metas,
-                data: &instruction_data,
-            };
+            builder.data(&[#(#discriminator),*])?;
         }
     } else {
         quote! {
-            let mut instruction_data = [bytes::UNINIT_BYTE; 1232];
-            unsafe {
-                let ptr = instruction_data.as_mut_ptr() as *mut u8;
-                core::ptr::copy_nonoverlapping(#discriminator_expr.as_ptr(), ptr, #discriminator_len);
-            }
-
-            let data_len = #discriminator_len #(+ #arg_sizes)*;
-            let mut writer = &mut instruction_data[#discriminator_len..];
+            builder.data(&[#(#discriminator),*])?;
+            let args_len = #(#arg_sizes)+*;
+            // SAFETY: The reserved bytes are fully written by the serialization of the arguments.
+            let mut writer = unsafe { builder.reserve_data(args_len)? };
             #(#arg_ser)*
-
-            let instruction = instruction::InstructionView {
-                program_id: &PROGRAM_ID,
-                accounts: &account_metas,
-                data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as *const u8, data_len) },
-            };
         }
     };
 
-    (arg_fields, instruction_data)
+    (
+        arg_fields,
+        data_len.unwrap_or(MAX_INSTRUCTION_DATA_LEN),
+        instruction_data,
+    )
+}
+
+fn gen_return_methods(returns: Option<&IdlType>) -> Option<TokenStream> {
+    let ty = gen_type(returns?);
+
+    Some(quote! {
+        /// Invokes the instruction and decodes the return data set by the callee.
+        #[inline(always)]
+        pub fn invoke_and_get_return(&self) -> ProgramResult<#ty> {
+            self.invoke_signed_and_get_return(&[])
+        }
+
+        #[inline(always)]
+        pub fn invoke_signed_and_get_return(&self, seeds: &[CpiSigner]) -> ProgramResult<#ty> {
+            self.invoke_signed(seeds)?;
+
+            let return_data = return_data::get_return_data_from(&PROGRAM_ID)?;
+            <BorshStrategy<false> as Accessor<'_, #ty>>::access(return_data.as_slice())
+                .map_err(|_| ErrorCode::InvalidReturnData.into())
+        }
+    })
 }
 
 fn gen_account_instruction(
     accounts: &[InstructionAccountItem],
 ) -> (Vec<TokenStream>, Vec<syn::Ident>) {
-    let mut metas = Vec::with_capacity(accounts.len());
+    let mut pushes = Vec::with_capacity(accounts.len());
     let mut fields = Vec::with_capacity(accounts.len());
 
     for account in accounts {
         match account {
             InstructionAccountItem::Composite(composite_accounts) => {
-                let (nested_metas, nested_fields) =
+                let (nested_pushes, nested_fields) =
                     gen_account_instruction(&composite_accounts.accounts);
-                metas.extend(nested_metas);
+                pushes.extend(nested_pushes);
                 fields.extend(nested_fields);
             }
             InstructionAccountItem::Single(account) => {
@@ -153,24 +195,15 @@ fn gen_account_instruction(
                 let is_writable = account.is_mut;
                 let is_signer = account.is_signer;
 
-                metas.push(quote! {
-                    instruction::InstructionAccount::new(self.#ident.address(), #is_writable, #is_signer)
+                pushes.push(quote! {
+                    builder.account(self.#ident, #is_writable, #is_signer)?;
                 });
                 fields.push(ident);
             }
         }
     }
 
-    (metas, fields)
-}
-
-#[inline]
-fn gen_account_metas(metas: &[TokenStream]) -> TokenStream {
-    let len = metas.len();
-
-    quote! {
-        let account_metas: [instruction::InstructionAccount; #len] = [#(#metas),*];
-    }
+    (pushes, fields)
 }
 
 #[cfg(test)]
@@ -182,17 +215,12 @@ mod tests {
         let args = vec![];
         let discriminator = vec![1, 2, 3, 4];
 
-        let (fields, data) = gen_instruction_data(&args, &discriminator);
+        let (fields, data_len, data) = gen_instruction_data(&args, &discriminator);
         let expected_data = quote! {
-            let instruction_data = [1u8, 2u8, 3u8, 4u8];
-
-            let instruction = instruction::InstructionView {
-                program_id: &PROGRAM_ID,
-                accounts: &account_metas,
-                data: &instruction_data,
-            };
+            builder.data(&[1u8, 2u8, 3u8, 4u8])?;
         };
         assert!(fields.is_empty());
+        assert_eq!(data_len, 4);
         assert_eq!(data.to_string(), expected_data.to_string());
 
         let args = vec![Field {
@@ -200,28 +228,28 @@ mod tests {
             name: "amount".to_string(),
             ty: crate::idl::Type::U64,
         }];
-        let discriminator = vec![1, 2, 3, 4];
 
-        let (fields, data) = gen_instruction_data(&args, &discriminator);
+        let (fields, data_len, data) = gen_instruction_data(&args, &discriminator);
         let expected_data = quote! {
-            let mut instruction_data = [bytes::UNINIT_BYTE; 1232];
-            unsafe {
-                let ptr = instruction_data.as_mut_ptr() as *mut u8;
-                core::ptr::copy_nonoverlapping([1u8, 2u8, 3u8, 4u8].as_ptr(), ptr, 4usize);
-            }
-            let data_len = 4usize + BorshStrategy::<false>::size(&self.amount)?;
-            let mut writer = &mut instruction_data[4usize..];
+            builder.data(&[1u8, 2u8, 3u8, 4u8])?;
+            let args_len = BorshStrategy::<false>::size(&self.amount)?;
+            // SAFETY: The reserved bytes are fully written by the serialization of the arguments.
+            let mut writer = unsafe { builder.reserve_data(args_len)? };
             BorshStrategy::<false>::write_into(&mut writer, &self.amount)?;
-
-            let instruction = instruction::InstructionView {
-                program_id: &PROGRAM_ID,
-                accounts: &account_metas,
-                data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as *const u8, data_len) },
-            };
         };
 
         assert_eq!(fields.len(), 1);
+        assert_eq!(data_len, 12);
         assert_eq!(data.to_string(), expected_data.to_string());
+
+        let args = vec![Field {
+            docs: vec![],
+            name: "name".to_string(),
+            ty: crate::idl::Type::String,
+        }];
+
+        let (_, data_len, _) = gen_instruction_data(&args, &discriminator);
+        assert_eq!(data_len, MAX_INSTRUCTION_DATA_LEN);
     }
 
     #[test]
@@ -239,14 +267,14 @@ mod tests {
             }),
         ];
 
-        let (metas, fields) = gen_account_instruction(&accounts);
+        let (pushes, fields) = gen_account_instruction(&accounts);
 
         let result = quote! {
-            #(#metas),*
+            #(#pushes)*
         };
         let expected = quote! {
-            instruction::InstructionAccount::new(self.test_account.address(), true, false),
-            instruction::InstructionAccount::new(self.test_account2.address(), false, true)
+            builder.account(self.test_account, true, false)?;
+            builder.account(self.test_account2, false, true)?;
         };
 
         assert_eq!(result.to_string(), expected.to_string());
@@ -255,11 +283,25 @@ mod tests {
     }
 
     #[test]
-    fn test_gen_account_metas() {
-        let metas = vec![quote!(meta1), quote!(meta2)];
-        let result = gen_account_metas(&metas);
+    fn test_gen_return_methods() {
+        assert!(gen_return_methods(None).is_none());
+
+        let result = gen_return_methods(Some(&IdlType::U64)).unwrap();
         let expected = quote! {
-            let account_metas: [instruction::InstructionAccount; 2usize] = [meta1, meta2];
+            /// Invokes the instruction and decodes the return data set by the callee.
+            #[inline(always)]
+            pub fn invoke_and_get_return(&self) -> ProgramResult<u64> {
+                self.invoke_signed_and_get_return(&[])
+            }
+
+            #[inline(always)]
+            pub fn invoke_signed_and_get_return(&self, seeds: &[CpiSigner]) -> ProgramResult<u64> {
+                self.invoke_signed(seeds)?;
+
+                let return_data = return_data::get_return_data_from(&PROGRAM_ID)?;
+                <BorshStrategy<false> as Accessor<'_, u64>>::access(return_data.as_slice())
+                    .map_err(|_| ErrorCode::InvalidReturnData.into())
+            }
         };
 
         assert_eq!(result.to_string(), expected.to_string());
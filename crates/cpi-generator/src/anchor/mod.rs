@@ -1,8 +1,9 @@
 mod account;
 mod cpi;
 mod doc;
+mod error;
 mod instruction;
 mod program_id;
 mod r#type;
 
-pub use {account::*, cpi::*, doc::*, instruction::*, r#type::*};
+pub use {account::*, cpi::*, doc::*, error::*, instruction::*, r#type::*};
@@ -4,11 +4,11 @@ use {
 };
 
 pub fn gen_program_id(name: &str, address: &str) -> proc_macro2::TokenStream {
-    let name = &name.to_upper_camel_case();
-    let ident = format_ident!("{name}Program");
+    let ident = format_ident!("{}Program", name.to_upper_camel_case());
 
     quote! {
         pub const PROGRAM_ID: Address = Address::from_str_const(#address);
+        pub const PROGRAM_NAME: &str = #name;
 
         pub struct #ident;
 
@@ -31,6 +31,7 @@ mod tests {
             gen_program_id("test", "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS").to_string();
         let expected = quote! {
             pub const PROGRAM_ID: Address = Address::from_str_const("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+            pub const PROGRAM_NAME: &str = "test";
 
             pub struct TestProgram;
 
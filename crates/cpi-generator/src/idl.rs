@@ -230,6 +230,13 @@ pub struct Account {
     pub ty: Option<TypeDefTy>,
 }
 
+#[derive(Deserialize)]
+pub struct ErrorCode {
+    pub code: u32,
+    pub name: String,
+    pub msg: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct Idl {
     pub name: Option<String>,
@@ -239,4 +246,6 @@ pub struct Idl {
     pub instructions: Vec<Instruction>,
     #[serde(default)]
     pub types: Vec<TypeDef>,
+    #[serde(default)]
+    pub errors: Vec<ErrorCode>,
 }
@@ -26,6 +26,29 @@ impl<'a> DiscriminatorBuilder<'a> {
 
         [b0, b1, b2, b3, self.layout_version, 0, 0, 0]
     }
+
+    /// Builds a compact discriminator from the first `N` bytes of `sha256(name)`.
+    pub const fn build_compact<const N: usize>(self) -> [u8; N] {
+        let hash = Sha256::new().update(self.name.as_bytes()).finalize();
+        let mut discriminator = [0; N];
+        let mut i = 0;
+        while i < N {
+            discriminator[i] = hash[i];
+            i += 1;
+        }
+        discriminator
+    }
+
+    /// Builds the Anchor account discriminator, `sha256("account:<name>")[..8]`.
+    pub const fn build_anchor(self) -> [u8; 8] {
+        let hash = Sha256::new()
+            .update(b"account:")
+            .update(self.name.as_bytes())
+            .finalize();
+        let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = hash;
+
+        [b0, b1, b2, b3, b4, b5, b6, b7]
+    }
 }
 
 #[cfg(test)]
@@ -44,4 +67,24 @@ mod tests {
 
         assert_eq!(discriminator, expected);
     }
+
+    #[test]
+    fn compact_discriminator_test() {
+        assert_eq!(
+            DiscriminatorBuilder::new("state").build_compact::<1>(),
+            [75]
+        );
+        assert_eq!(
+            DiscriminatorBuilder::new("state").build_compact::<2>(),
+            [75, 166]
+        );
+    }
+
+    #[test]
+    fn anchor_discriminator_test() {
+        let discriminator = DiscriminatorBuilder::new("Counter").build_anchor();
+        let expected = [255, 176, 4, 245, 188, 253, 124, 25];
+
+        assert_eq!(discriminator, expected);
+    }
 }
@@ -14,6 +14,16 @@ pub enum ErrorCode {
     InvalidReturnData,
     InvalidDataLength,
     InvalidDataAlignment,
+    RequireEqViolated,
+    RequireNeqViolated,
+    RequireKeysEqViolated,
+    RequireGtViolated,
+    RequireGteViolated,
+    RequireOwnedByViolated,
+    InstructionConstraint,
+    SignatureVerificationMissing,
+    StakeConstraintViolated,
+    AccountClosed,
 }
 
 impl TryFrom<u32> for ErrorCode {
@@ -33,6 +43,16 @@ impl TryFrom<u32> for ErrorCode {
             109 => Ok(ErrorCode::InvalidReturnData),
             110 => Ok(ErrorCode::InvalidDataLength),
             111 => Ok(ErrorCode::InvalidDataAlignment),
+            112 => Ok(ErrorCode::RequireEqViolated),
+            113 => Ok(ErrorCode::RequireNeqViolated),
+            114 => Ok(ErrorCode::RequireKeysEqViolated),
+            115 => Ok(ErrorCode::RequireGtViolated),
+            116 => Ok(ErrorCode::RequireGteViolated),
+            117 => Ok(ErrorCode::RequireOwnedByViolated),
+            118 => Ok(ErrorCode::InstructionConstraint),
+            119 => Ok(ErrorCode::SignatureVerificationMissing),
+            120 => Ok(ErrorCode::StakeConstraintViolated),
+            121 => Ok(ErrorCode::AccountClosed),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -63,6 +83,18 @@ impl ToStr for ErrorCode {
             ErrorCode::InvalidReturnData => "Error: The return data is invalid",
             ErrorCode::InvalidDataLength => "Error: Invalid data length",
             ErrorCode::InvalidDataAlignment => "Error: Invalid data alignment",
+            ErrorCode::RequireEqViolated => "Error: require_eq violated",
+            ErrorCode::RequireNeqViolated => "Error: require_neq violated",
+            ErrorCode::RequireKeysEqViolated => "Error: require_keys_eq violated",
+            ErrorCode::RequireGtViolated => "Error: require_gt violated",
+            ErrorCode::RequireGteViolated => "Error: require_gte violated",
+            ErrorCode::RequireOwnedByViolated => "Error: require_owned_by violated",
+            ErrorCode::InstructionConstraint => "Error: instruction constraint violated",
+            ErrorCode::SignatureVerificationMissing => {
+                "Error: No instruction verifies the expected signature"
+            }
+            ErrorCode::StakeConstraintViolated => "Error: Stake constraint was violated",
+            ErrorCode::AccountClosed => "Error: The account was closed",
         }
     }
 }
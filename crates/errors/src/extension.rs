@@ -1,11 +1,125 @@
-use crate::Error;
+use {crate::Error, solana_program_error::ProgramError};
 
 pub trait ResultExtension {
     fn trace_account(self, name: &'static str) -> Self;
+
+    fn trace_constraint(self, name: &'static str, constraint: &'static str) -> Self;
+
+    fn trace_program(self, name: &'static str) -> Self;
+
+    /// Converts a `ProgramError::Custom` code that `C` recognizes with `map`.
+    ///
+    /// Other errors are returned unchanged.
+    fn map_custom_err<C, E>(self, map: impl FnOnce(C) -> E) -> Self
+    where
+        C: TryFrom<u32>,
+        E: Into<Error>;
 }
 
 impl<T> ResultExtension for Result<T, Error> {
     fn trace_account(self, name: &'static str) -> Self {
         self.map_err(|err| err.with_account(name))
     }
+
+    fn trace_constraint(self, name: &'static str, constraint: &'static str) -> Self {
+        self.map_err(|err| err.with_account(name).with_constraint(constraint))
+    }
+
+    fn trace_program(self, name: &'static str) -> Self {
+        self.map_err(|err| err.with_program(name))
+    }
+
+    fn map_custom_err<C, E>(self, map: impl FnOnce(C) -> E) -> Self
+    where
+        C: TryFrom<u32>,
+        E: Into<Error>,
+    {
+        self.map_err(|err| match err.error {
+            ProgramError::Custom(code) => match C::try_from(code) {
+                Ok(callee) => map(callee).into(),
+                Err(_) => err,
+            },
+            _ => err,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::ErrorCode};
+
+    /// Errors of a callee program, as decoded from its `Custom` codes.
+    enum CalleeError {
+        Paused,
+    }
+
+    impl TryFrom<u32> for CalleeError {
+        type Error = ();
+
+        fn try_from(code: u32) -> Result<Self, Self::Error> {
+            match code {
+                6000 => Ok(CalleeError::Paused),
+                _ => Err(()),
+            }
+        }
+    }
+
+    /// Runs the conversion the generated `invoke_signed_mapped` applies to the CPI result.
+    fn map_callee(err: impl Into<Error>) -> Error {
+        Err::<(), _>(err.into())
+            .map_custom_err(|err: CalleeError| match err {
+                CalleeError::Paused => ErrorCode::InvalidReturnData,
+            })
+            .trace_program("callee")
+            .unwrap_err()
+    }
+
+    #[test]
+    fn trace_constraint() {
+        let err = Err::<(), _>(Error::from(ErrorCode::HasOneConstraint))
+            .trace_constraint("config", "has_one")
+            .unwrap_err();
+
+        assert_eq!(err.account_name(), Some("config"));
+        assert_eq!(err.constraint(), Some("has_one"));
+        assert_eq!(err.program_name(), None);
+        assert_eq!(
+            ProgramError::from(err),
+            ProgramError::from(ErrorCode::HasOneConstraint)
+        );
+    }
+
+    #[test]
+    fn trace_account() {
+        let err = Err::<(), _>(Error::from(ErrorCode::AccountNotSigner))
+            .trace_account("authority")
+            .unwrap_err();
+
+        assert_eq!(err.account_name(), Some("authority"));
+        assert_eq!(err.constraint(), None);
+    }
+
+    #[test]
+    fn map_custom_err() {
+        let err = map_callee(ProgramError::Custom(6000));
+        assert_eq!(err.program_name(), Some("callee"));
+        assert_eq!(
+            ProgramError::from(err),
+            ProgramError::from(ErrorCode::InvalidReturnData)
+        );
+    }
+
+    #[test]
+    fn map_custom_err_passthrough() {
+        let err = map_callee(ProgramError::Custom(42));
+        assert_eq!(err.program_name(), Some("callee"));
+        assert_eq!(ProgramError::from(err), ProgramError::Custom(42));
+
+        let err = map_callee(ProgramError::InvalidAccountData);
+        assert_eq!(ProgramError::from(err), ProgramError::InvalidAccountData);
+
+        assert!(Ok::<_, Error>(())
+            .map_custom_err(|_: CalleeError| ErrorCode::InvalidReturnData)
+            .is_ok());
+    }
 }
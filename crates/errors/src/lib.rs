@@ -1,8 +1,24 @@
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
 mod error_code;
 mod extension;
+mod macros;
+#[cfg(feature = "logging")]
+mod msg;
 
+#[doc(hidden)]
+#[cfg(feature = "logging")]
+pub mod __private {
+    pub use crate::{
+        msg::{buffer_of, placeholders, Concat, MessageWriter},
+        LogLoggable, LogProbe, LogUnloggable,
+    };
+}
+#[cfg(feature = "logging")]
+pub use msg::{LogBuffer, LogValue, MAX_UNSIZED_LEN};
 pub use {error_code::*, extension::*};
 use {
     solana_address::error::AddressError,
@@ -12,6 +28,8 @@ use {
 pub struct Error {
     error: ProgramError,
     account_name: Option<&'static str>,
+    program_name: Option<&'static str>,
+    constraint: Option<&'static str>,
 }
 
 impl Error {
@@ -19,6 +37,8 @@ impl Error {
         Error {
             error: error.into(),
             account_name: None,
+            program_name: None,
+            constraint: None,
         }
     }
 
@@ -31,6 +51,24 @@ impl Error {
         self.account_name
     }
 
+    pub fn with_program(mut self, name: &'static str) -> Self {
+        self.program_name = Some(name);
+        self
+    }
+
+    pub fn program_name(&self) -> Option<&str> {
+        self.program_name
+    }
+
+    pub fn with_constraint(mut self, constraint: &'static str) -> Self {
+        self.constraint = Some(constraint);
+        self
+    }
+
+    pub fn constraint(&self) -> Option<&str> {
+        self.constraint
+    }
+
     pub fn to_str<E>(&self) -> &'static str
     where
         E: ToStr + TryFrom<u32> + 'static,
@@ -49,6 +87,8 @@ impl From<ProgramError> for Error {
         Error {
             error,
             account_name: None,
+            program_name: None,
+            constraint: None,
         }
     }
 }
@@ -58,6 +98,8 @@ impl From<ErrorCode> for Error {
         Error {
             error: value.into(),
             account_name: None,
+            program_name: None,
+            constraint: None,
         }
     }
 }
@@ -73,6 +115,8 @@ impl From<AddressError> for Error {
         Self {
             error: value.into(),
             account_name: None,
+            program_name: None,
+            constraint: None,
         }
     }
 }
@@ -94,13 +138,108 @@ where
         logger.append(unsafe { str::from_utf8_unchecked(account_name.as_bytes()) });
         logger.log();
     }
+
+    if let Some(constraint) = error.constraint() {
+        let mut logger = solana_program_log::Logger::<50>::default();
+        logger.append("Constraint: ");
+        logger.append(constraint);
+        logger.log();
+    }
+
+    if let Some(program_name) = error.program_name() {
+        let mut logger = solana_program_log::Logger::<50>::default();
+        logger.append("Program origin: ");
+        logger.append(program_name);
+        logger.log();
+    }
 }
 
-#[macro_export]
-macro_rules! require {
-    ( $constraint:expr, $error:expr ) => {
-        if pinocchio::hint::unlikely(!$constraint) {
-            return Err($error.into());
-        }
-    };
+#[cfg(feature = "logging")]
+struct LogRef<'a, T: ?Sized>(&'a T);
+
+#[cfg(feature = "logging")]
+unsafe impl<T> solana_program_log::logger::Log for LogRef<'_, T>
+where
+    T: solana_program_log::logger::Log + ?Sized,
+{
+    #[inline(always)]
+    fn write_with_args(
+        &self,
+        buffer: &mut [core::mem::MaybeUninit<u8>],
+        args: &[solana_program_log::Argument],
+    ) -> usize {
+        self.0.write_with_args(buffer, args)
+    }
+}
+
+/// Logs both sides of a failed `require_*` comparison.
+#[cfg(feature = "logging")]
+#[cold]
+#[doc(hidden)]
+pub fn log_values<L, R>(left: &L, right: &R)
+where
+    L: solana_program_log::logger::Log + ?Sized,
+    R: solana_program_log::logger::Log + ?Sized,
+{
+    let mut logger = solana_program_log::Logger::<64>::default();
+    logger.append("Left: ").append(LogRef(left)).log();
+    logger.clear();
+    logger.append("Right: ").append(LogRef(right)).log();
+}
+
+/// Both sides of a failed `require_*` comparison, logged only when their types implement
+/// `Log`.
+///
+/// `require_*` macros call `(&LogProbe(left, right)).log_values()`: method resolution picks
+/// [`LogLoggable`] when both types implement `Log` and falls back to the no-op [`LogUnloggable`] otherwise.
+#[cfg(feature = "logging")]
+#[doc(hidden)]
+pub struct LogProbe<'a, L: ?Sized, R: ?Sized>(pub &'a L, pub &'a R);
+
+#[cfg(feature = "logging")]
+#[doc(hidden)]
+pub trait LogLoggable {
+    fn log_values(&self);
+}
+
+#[cfg(feature = "logging")]
+impl<L, R> LogLoggable for LogProbe<'_, L, R>
+where
+    L: solana_program_log::logger::Log + ?Sized,
+    R: solana_program_log::logger::Log + ?Sized,
+{
+    #[inline(always)]
+    fn log_values(&self) {
+        log_values(self.0, self.1);
+    }
+}
+
+#[cfg(feature = "logging")]
+#[doc(hidden)]
+pub trait LogUnloggable {
+    fn log_values(&self);
+}
+
+#[cfg(feature = "logging")]
+impl<L: ?Sized, R: ?Sized> LogUnloggable for &LogProbe<'_, L, R> {
+    #[inline(always)]
+    fn log_values(&self) {}
+}
+
+/// Logs the addresses of a failed address constraint with [`msg!`].
+///
+/// Called by the checks generated by `#[context]`; does nothing without the `logging` feature.
+#[cold]
+#[doc(hidden)]
+pub fn log_address_mismatch(expected: &solana_address::Address, found: &solana_address::Address) {
+    msg!("Expected {}, found {}", expected, found);
+}
+
+/// Logs both sides of a failed address comparison in base58.
+#[cfg(feature = "logging")]
+#[cold]
+#[doc(hidden)]
+pub fn log_addresses(left: &solana_address::Address, right: &solana_address::Address) {
+    msg!("Left: {}", left);
+    msg!("Right: {}", right);
 }
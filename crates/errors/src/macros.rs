@@ -0,0 +1,317 @@
+/// Returns `$error` when `$constraint` is false.
+#[macro_export]
+macro_rules! require {
+    ( $constraint:expr, $error:expr $(,)? ) => {
+        if pinocchio::hint::unlikely(!$constraint) {
+            return Err($error.into());
+        }
+    };
+}
+
+/// Ensures two values are equal.
+///
+/// Defaults to [`ErrorCode::RequireEqViolated`](crate::ErrorCode::RequireEqViolated).
+/// With the `logging` feature, both values are logged on failure when their types implement
+/// `Log`.
+#[macro_export]
+macro_rules! require_eq {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::require_eq!($left, $right, $crate::ErrorCode::RequireEqViolated)
+    };
+    ( $left:expr, $right:expr, $error:expr $(,)? ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(*left != *right) {
+                    #[cfg(feature = "logging")]
+                    {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{LogLoggable as _, LogUnloggable as _};
+                        (&$crate::__private::LogProbe(left, right)).log_values();
+                    }
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Ensures two values are not equal.
+///
+/// Defaults to [`ErrorCode::RequireNeqViolated`](crate::ErrorCode::RequireNeqViolated).
+/// With the `logging` feature, both values are logged on failure when their types implement
+/// `Log`.
+#[macro_export]
+macro_rules! require_neq {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::require_neq!($left, $right, $crate::ErrorCode::RequireNeqViolated)
+    };
+    ( $left:expr, $right:expr, $error:expr $(,)? ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(*left == *right) {
+                    #[cfg(feature = "logging")]
+                    {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{LogLoggable as _, LogUnloggable as _};
+                        (&$crate::__private::LogProbe(left, right)).log_values();
+                    }
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Ensures two addresses are equal, using `address_eq` for the comparison.
+///
+/// Defaults to [`ErrorCode::RequireKeysEqViolated`](crate::ErrorCode::RequireKeysEqViolated).
+/// With the `logging` feature, both addresses are logged on failure.
+#[macro_export]
+macro_rules! require_keys_eq {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::require_keys_eq!($left, $right, $crate::ErrorCode::RequireKeysEqViolated)
+    };
+    ( $left:expr, $right:expr, $error:expr $(,)? ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let (left, right): (&pinocchio::Address, &pinocchio::Address) =
+                    (::core::borrow::Borrow::borrow(left), ::core::borrow::Borrow::borrow(right));
+                if pinocchio::hint::unlikely(!pinocchio::address::address_eq(left, right)) {
+                    #[cfg(feature = "logging")]
+                    $crate::log_addresses(left, right);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Ensures `$left` is strictly greater than `$right`.
+///
+/// Defaults to [`ErrorCode::RequireGtViolated`](crate::ErrorCode::RequireGtViolated).
+/// With the `logging` feature, both values are logged on failure when their types implement
+/// `Log`.
+#[macro_export]
+macro_rules! require_gt {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::require_gt!($left, $right, $crate::ErrorCode::RequireGtViolated)
+    };
+    ( $left:expr, $right:expr, $error:expr $(,)? ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(*left <= *right) {
+                    #[cfg(feature = "logging")]
+                    {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{LogLoggable as _, LogUnloggable as _};
+                        (&$crate::__private::LogProbe(left, right)).log_values();
+                    }
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Ensures `$left` is greater than or equal to `$right`.
+///
+/// Defaults to [`ErrorCode::RequireGteViolated`](crate::ErrorCode::RequireGteViolated).
+/// With the `logging` feature, both values are logged on failure when their types implement
+/// `Log`.
+#[macro_export]
+macro_rules! require_gte {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::require_gte!($left, $right, $crate::ErrorCode::RequireGteViolated)
+    };
+    ( $left:expr, $right:expr, $error:expr $(,)? ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if pinocchio::hint::unlikely(*left < *right) {
+                    #[cfg(feature = "logging")]
+                    {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{LogLoggable as _, LogUnloggable as _};
+                        (&$crate::__private::LogProbe(left, right)).log_values();
+                    }
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Ensures an account is owned by the given program.
+///
+/// Defaults to [`ErrorCode::RequireOwnedByViolated`](crate::ErrorCode::RequireOwnedByViolated).
+/// With the `logging` feature, the actual and expected owners are logged on failure.
+#[macro_export]
+macro_rules! require_owned_by {
+    ( $account:expr, $owner:expr $(,)? ) => {
+        $crate::require_owned_by!($account, $owner, $crate::ErrorCode::RequireOwnedByViolated)
+    };
+    ( $account:expr, $owner:expr, $error:expr $(,)? ) => {
+        match (&$account, &$owner) {
+            (account, owner) => {
+                let account: &pinocchio::AccountView = ::core::convert::AsRef::as_ref(account);
+                let owner: &pinocchio::Address = ::core::borrow::Borrow::borrow(owner);
+                if pinocchio::hint::unlikely(!account.owned_by(owner)) {
+                    #[cfg(feature = "logging")]
+                    $crate::log_addresses(unsafe { account.owner() }, owner);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+/// Logs a message formatted without allocation.
+///
+/// Each `{}` placeholder is replaced by the next argument: integers and `bool` in
+/// decimal, `Address` in base58, byte slices in hex and `&str` as is. The message is
+/// written into a stack buffer sized at compile time from the format string and the
+/// [`LogValue`](crate::LogValue) of each argument. Byte slices and `&str` are
+/// truncated to [`MAX_UNSIZED_LEN`](crate::MAX_UNSIZED_LEN) bytes.
+///
+/// Expands to nothing without the `logging` feature.
+#[macro_export]
+macro_rules! msg {
+    ( $fmt:literal $(, $arg:expr )* $(,)? ) => {{
+        #[cfg(feature = "logging")]
+        $crate::__msg!(@bind [] $fmt; $($arg),*);
+
+        #[cfg(not(feature = "logging"))]
+        if false {
+            let _ = ($(&$arg,)*);
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __msg {
+    (@bind [$($bound:ident)*] $fmt:literal; $arg:expr $(, $rest:expr)*) => {
+        match &$arg {
+            value => $crate::__msg!(@bind [$($bound)* value] $fmt; $($rest),*),
+        }
+    };
+    (@bind [$($bound:ident)*] $fmt:literal;) => {{
+        const FMT: &str = $fmt;
+        const PLACEHOLDERS: [usize; $crate::__msg!(@count $($bound)*)] =
+            $crate::__private::placeholders(FMT);
+
+        let mut buffer = $crate::__private::Concat(
+            [::core::mem::MaybeUninit::<u8>::uninit(); FMT.len()],
+            $crate::__msg!(@buffer $($bound)*),
+        );
+        let mut writer = $crate::__private::MessageWriter::new(&mut buffer, FMT, &PLACEHOLDERS);
+        $( writer.append($bound); )*
+        writer.log();
+    }};
+    (@buffer) => {
+        [::core::mem::MaybeUninit::<u8>::uninit(); 0]
+    };
+    (@buffer $value:ident $($rest:ident)*) => {
+        $crate::__private::Concat($crate::__private::buffer_of($value), $crate::__msg!(@buffer $($rest)*))
+    };
+    (@count $($bound:ident)*) => {
+        <[()]>::len(&[$($crate::__msg!(@unit $bound)),*])
+    };
+    (@unit $bound:ident) => {
+        ()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{Error, ErrorCode},
+        solana_address::Address,
+        solana_program_error::ProgramError,
+    };
+
+    #[derive(PartialEq, PartialOrd)]
+    struct Unloggable(u8);
+
+    /// Runs a `require_*` invocation and returns the error it produced, if any.
+    macro_rules! check {
+        ($require:expr) => {
+            (|| -> Result<(), Error> {
+                $require;
+                Ok(())
+            })()
+            .err()
+            .map(ProgramError::from)
+        };
+    }
+
+    #[test]
+    fn require() {
+        assert_eq!(check!(require!(true, ErrorCode::InvalidReturnData)), None);
+        assert_eq!(
+            check!(require!(false, ErrorCode::InvalidReturnData)),
+            Some(ErrorCode::InvalidReturnData.into())
+        );
+    }
+
+    #[test]
+    fn require_comparisons_with_loggable_values() {
+        assert_eq!(check!(require_eq!(1u64, 1u64)), None);
+        assert_eq!(
+            check!(require_eq!(1u64, 2u64)),
+            Some(ErrorCode::RequireEqViolated.into())
+        );
+        assert_eq!(check!(require_neq!(1u64, 2u64)), None);
+        assert_eq!(
+            check!(require_neq!(1u64, 1u64, ProgramError::InvalidArgument)),
+            Some(ProgramError::InvalidArgument)
+        );
+        assert_eq!(check!(require_gt!(2i32, 1i32)), None);
+        assert_eq!(
+            check!(require_gt!(1i32, 1i32)),
+            Some(ErrorCode::RequireGtViolated.into())
+        );
+        assert_eq!(check!(require_gte!(1u8, 1u8)), None);
+        assert_eq!(
+            check!(require_gte!(0u8, 1u8)),
+            Some(ErrorCode::RequireGteViolated.into())
+        );
+    }
+
+    #[test]
+    fn require_comparisons_with_unloggable_values() {
+        assert_eq!(check!(require_eq!(Unloggable(1), Unloggable(1))), None);
+        assert_eq!(
+            check!(require_eq!(Unloggable(1), Unloggable(2))),
+            Some(ErrorCode::RequireEqViolated.into())
+        );
+        assert_eq!(
+            check!(require_neq!(Unloggable(1), Unloggable(1))),
+            Some(ErrorCode::RequireNeqViolated.into())
+        );
+        assert_eq!(
+            check!(require_gt!(Unloggable(1), Unloggable(1))),
+            Some(ErrorCode::RequireGtViolated.into())
+        );
+        assert_eq!(
+            check!(require_gte!(Unloggable(0), Unloggable(1))),
+            Some(ErrorCode::RequireGteViolated.into())
+        );
+        assert_eq!(
+            check!(require_eq!(Address::default(), Address::new_from_array([1; 32]))),
+            Some(ErrorCode::RequireEqViolated.into())
+        );
+    }
+
+    #[test]
+    fn require_keys_eq() {
+        let key = Address::new_from_array([1; 32]);
+        assert_eq!(
+            check!(require_keys_eq!(&key, Address::new_from_array([1; 32]))),
+            None
+        );
+        assert_eq!(
+            check!(require_keys_eq!(&key, Address::default())),
+            Some(ErrorCode::RequireKeysEqViolated.into())
+        );
+    }
+}
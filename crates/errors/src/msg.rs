@@ -0,0 +1,428 @@
+use {
+    core::mem::MaybeUninit,
+    solana_address::Address,
+    solana_program_log::logger::{log_message, Log},
+};
+
+/// Maximum number of bytes written for unsized values (`&str` and byte slices).
+pub const MAX_UNSIZED_LEN: usize = 64;
+
+/// Stack buffer made only of uninitialized bytes.
+///
+/// # Safety
+///
+/// Implementors must have an alignment of 1 and no padding, so that they can be
+/// viewed as a `[MaybeUninit<u8>]` of `size_of::<Self>()` bytes.
+#[doc(hidden)]
+pub unsafe trait LogBuffer: Sized {
+    const UNINIT: Self;
+}
+
+unsafe impl<const N: usize> LogBuffer for [MaybeUninit<u8>; N] {
+    const UNINIT: Self = [MaybeUninit::uninit(); N];
+}
+
+/// Two buffers laid out back to back.
+#[doc(hidden)]
+#[repr(C)]
+pub struct Concat<A, B>(pub A, pub B);
+
+unsafe impl<A: LogBuffer, B: LogBuffer> LogBuffer for Concat<A, B> {
+    const UNINIT: Self = Concat(A::UNINIT, B::UNINIT);
+}
+
+/// A value that can be formatted by [`msg!`](crate::msg).
+///
+/// # Safety
+///
+/// `write` must initialize every byte it reports as written and never report
+/// more than `size_of::<Self::Buffer>()` bytes.
+pub unsafe trait LogValue {
+    /// Buffer large enough for the longest encoding of the value.
+    type Buffer: LogBuffer;
+
+    /// Writes the encoded value and returns the number of bytes written.
+    ///
+    /// `buffer` is at least `size_of::<Self::Buffer>()` bytes long.
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize;
+}
+
+unsafe impl<T: LogValue + ?Sized> LogValue for &T {
+    type Buffer = T::Buffer;
+
+    #[inline(always)]
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+        (**self).write(buffer)
+    }
+}
+
+macro_rules! impl_log_value {
+    ( $( $ty:ty => $len:expr ),* $(,)? ) => {
+        $(
+            unsafe impl LogValue for $ty {
+                type Buffer = [MaybeUninit<u8>; $len];
+
+                #[inline(always)]
+                fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+                    Log::write(self, buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_log_value!(
+    u8 => 3,
+    u16 => 5,
+    u32 => 10,
+    u64 => 20,
+    u128 => 39,
+    usize => 20,
+    i8 => 4,
+    i16 => 6,
+    i32 => 11,
+    i64 => 20,
+    i128 => 40,
+    isize => 20,
+    bool => 5,
+);
+
+/// Truncated to [`MAX_UNSIZED_LEN`] bytes.
+unsafe impl LogValue for str {
+    type Buffer = [MaybeUninit<u8>; MAX_UNSIZED_LEN];
+
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+        let mut len = core::cmp::min(self.len(), MAX_UNSIZED_LEN);
+        while !self.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        for (dest, byte) in buffer.iter_mut().zip(&self.as_bytes()[..len]) {
+            dest.write(*byte);
+        }
+        len
+    }
+}
+
+/// Base58 encoded.
+unsafe impl LogValue for Address {
+    type Buffer = [MaybeUninit<u8>; 44];
+
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+        use core::fmt::Write;
+
+        struct SliceWriter<'a> {
+            buffer: &'a mut [MaybeUninit<u8>],
+            len: usize,
+        }
+
+        impl Write for SliceWriter<'_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                let dest = self.buffer.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+                for (d, s) in dest.iter_mut().zip(s.as_bytes()) {
+                    d.write(*s);
+                }
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut writer = SliceWriter { buffer, len: 0 };
+        let _ = write!(writer, "{self}");
+        writer.len
+    }
+}
+
+/// Hex encoded, truncated to [`MAX_UNSIZED_LEN`] characters.
+unsafe impl LogValue for [u8] {
+    type Buffer = [MaybeUninit<u8>; MAX_UNSIZED_LEN];
+
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let len = core::cmp::min(self.len(), MAX_UNSIZED_LEN / 2);
+        for (dest, byte) in buffer.chunks_exact_mut(2).zip(&self[..len]) {
+            dest[0].write(HEX[(byte >> 4) as usize]);
+            dest[1].write(HEX[(byte & 0x0f) as usize]);
+        }
+        len * 2
+    }
+}
+
+/// Hex encoded, truncated to [`MAX_UNSIZED_LEN`] characters.
+unsafe impl<const N: usize> LogValue for [u8; N] {
+    type Buffer = [MaybeUninit<u8>; MAX_UNSIZED_LEN];
+
+    #[inline(always)]
+    fn write(&self, buffer: &mut [MaybeUninit<u8>]) -> usize {
+        self.as_slice().write(buffer)
+    }
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn buffer_of<T: LogValue + ?Sized>(_value: &T) -> T::Buffer {
+    T::Buffer::UNINIT
+}
+
+/// Returns the byte offsets of the `N` `{}` placeholders in `fmt`.
+#[doc(hidden)]
+pub const fn placeholders<const N: usize>(fmt: &str) -> [usize; N] {
+    let bytes = fmt.as_bytes();
+    let mut positions = [0; N];
+    let mut count = 0;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            if count == N {
+                panic!("msg!: more placeholders than arguments");
+            }
+            positions[count] = i;
+            count += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    if count != N {
+        panic!("msg!: fewer placeholders than arguments");
+    }
+    positions
+}
+
+/// Interleaves the format string segments with the values in a [`LogBuffer`].
+#[doc(hidden)]
+pub struct MessageWriter<'a> {
+    buffer: &'a mut [MaybeUninit<u8>],
+    fmt: &'static str,
+    placeholders: &'a [usize],
+    index: usize,
+    cursor: usize,
+    len: usize,
+}
+
+impl<'a> MessageWriter<'a> {
+    #[inline(always)]
+    pub fn new<B: LogBuffer>(
+        buffer: &'a mut B,
+        fmt: &'static str,
+        placeholders: &'a [usize],
+    ) -> Self {
+        // SAFETY: `LogBuffer` implementors are byte arrays without padding.
+        let buffer = unsafe {
+            core::slice::from_raw_parts_mut(
+                buffer as *mut B as *mut MaybeUninit<u8>,
+                core::mem::size_of::<B>(),
+            )
+        };
+
+        MessageWriter {
+            buffer,
+            fmt,
+            placeholders,
+            index: 0,
+            cursor: 0,
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn write_segment(&mut self, end: usize) {
+        let segment = &self.fmt.as_bytes()[self.cursor..end];
+        for (dest, byte) in self.buffer[self.len..].iter_mut().zip(segment) {
+            dest.write(*byte);
+        }
+        self.len += segment.len();
+    }
+
+    #[inline(always)]
+    pub fn append<T: LogValue + ?Sized>(&mut self, value: &T) {
+        let placeholder = self.placeholders[self.index];
+        self.write_segment(placeholder);
+        self.cursor = placeholder + 2;
+        self.index += 1;
+        self.len += value.write(&mut self.buffer[self.len..]);
+    }
+
+    #[inline(always)]
+    fn finish(&mut self) -> &[u8] {
+        self.write_segment(self.fmt.len());
+        self.cursor = self.fmt.len();
+        // SAFETY: the first `len` bytes were written by the segments and values above.
+        unsafe { core::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, self.len) }
+    }
+
+    #[inline(always)]
+    pub fn log(mut self) {
+        log_message(self.finish());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Formats `$fmt` with `$args` the way `msg!` does and returns the message.
+    macro_rules! format_msg {
+        ($fmt:literal, |$message:ident| $check:expr $(, $arg:expr)* $(,)?) => {{
+            const FMT: &str = $fmt;
+            let placeholders = placeholders::<{ <[&str]>::len(&[$(stringify!($arg)),*]) }>(FMT);
+            let mut buffer = Concat(
+                [MaybeUninit::<u8>::uninit(); FMT.len()],
+                [MaybeUninit::<u8>::uninit(); 256],
+            );
+            let mut writer = MessageWriter::new(&mut buffer, FMT, &placeholders);
+            $( writer.append(&$arg); )*
+            let $message = writer.finish();
+            $check
+        }};
+    }
+
+    fn written<T: LogValue + ?Sized>(value: &T) -> ([MaybeUninit<u8>; 256], usize) {
+        let mut buffer = [MaybeUninit::uninit(); 256];
+        let len = value.write(&mut buffer);
+        assert!(len <= core::mem::size_of::<T::Buffer>());
+        (buffer, len)
+    }
+
+    fn assert_written<T: LogValue + ?Sized>(value: &T, expected: &[u8]) {
+        let (buffer, len) = written(value);
+        let bytes = unsafe { core::slice::from_raw_parts(buffer.as_ptr() as *const u8, len) };
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn integers_fit_their_buffer() {
+        assert_written(&u8::MAX, b"255");
+        assert_written(&u16::MAX, b"65535");
+        assert_written(&u32::MAX, b"4294967295");
+        assert_written(&u64::MAX, b"18446744073709551615");
+        assert_written(&u128::MAX, b"340282366920938463463374607431768211455");
+        assert_written(&i8::MIN, b"-128");
+        assert_written(&i16::MIN, b"-32768");
+        assert_written(&i32::MIN, b"-2147483648");
+        assert_written(&i64::MIN, b"-9223372036854775808");
+        assert_written(&i128::MIN, b"-170141183460469231731687303715884105728");
+        assert_written(&0u64, b"0");
+    }
+
+    #[test]
+    fn bool() {
+        assert_written(&true, b"true");
+        assert_written(&false, b"false");
+    }
+
+    #[test]
+    fn address_in_base58() {
+        assert_written(&Address::default(), b"11111111111111111111111111111111");
+        assert_written(
+            &Address::new_from_array([255; 32]),
+            b"JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG",
+        );
+    }
+
+    #[test]
+    fn bytes_in_hex() {
+        assert_written(&[0x00u8, 0x0f, 0xab, 0xff][..], b"000fabff");
+        assert_written(&[0x12u8; 2], b"1212");
+        assert_written(&[0u8; 0][..], b"");
+    }
+
+    #[test]
+    fn bytes_are_truncated() {
+        let (_, len) = written(&[0xffu8; 40][..]);
+        assert_eq!(len, MAX_UNSIZED_LEN);
+        assert_written(&[0xabu8; 33], &[b'a', b'b'].repeat(32));
+    }
+
+    #[test]
+    fn str_is_truncated() {
+        assert_written("typhoon", b"typhoon");
+
+        let long = "a".repeat(MAX_UNSIZED_LEN + 10);
+        assert_written(long.as_str(), &long.as_bytes()[..MAX_UNSIZED_LEN]);
+    }
+
+    #[test]
+    fn str_is_truncated_at_char_boundary() {
+        // `é` spans bytes 63 and 64, so it is dropped entirely.
+        let text = std::format!("{}é", "a".repeat(MAX_UNSIZED_LEN - 1));
+        assert_written(text.as_str(), &text.as_bytes()[..MAX_UNSIZED_LEN - 1]);
+
+        // A 4-byte char starting at byte 62 is dropped as well.
+        let text = std::format!("{}🌀", "a".repeat(MAX_UNSIZED_LEN - 2));
+        assert_written(text.as_str(), &text.as_bytes()[..MAX_UNSIZED_LEN - 2]);
+
+        // A multi-byte char ending exactly at the limit is kept.
+        let text = std::format!("{}é!", "a".repeat(MAX_UNSIZED_LEN - 2));
+        assert_written(text.as_str(), &text.as_bytes()[..MAX_UNSIZED_LEN]);
+    }
+
+    #[test]
+    fn placeholder_positions() {
+        assert_eq!(placeholders::<0>("no placeholder"), []);
+        assert_eq!(placeholders::<2>("{} and {}"), [0, 7]);
+        assert_eq!(placeholders::<1>("{ } {}"), [4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "more placeholders than arguments")]
+    fn more_placeholders_than_arguments() {
+        placeholders::<1>("{} {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer placeholders than arguments")]
+    fn fewer_placeholders_than_arguments() {
+        placeholders::<2>("{}");
+    }
+
+    #[test]
+    fn message_interleaves_segments_and_values() {
+        format_msg!("no arguments", |message| assert_eq!(message, b"no arguments"));
+        format_msg!(
+            "{} has {} lamports: {}",
+            |message| assert_eq!(message, b"00ff has 42 lamports: true"),
+            [0x00u8, 0xff],
+            42u64,
+            true,
+        );
+        format_msg!(
+            "owner={}, name={}",
+            |message| assert_eq!(message, b"owner=11111111111111111111111111111111, name=vault"),
+            Address::default(),
+            "vault",
+        );
+    }
+
+    #[test]
+    fn message_fills_its_buffer_exactly() {
+        const FMT: &str = "[{}|{}|{}]";
+        let placeholders = placeholders::<3>(FMT);
+        let long = "z".repeat(MAX_UNSIZED_LEN * 2);
+        let mut buffer = Concat(
+            [MaybeUninit::<u8>::uninit(); FMT.len()],
+            Concat(
+                buffer_of(&i128::MIN),
+                Concat(
+                    buffer_of(&Address::new_from_array([255; 32])),
+                    buffer_of(long.as_str()),
+                ),
+            ),
+        );
+        let capacity = core::mem::size_of_val(&buffer) - 6;
+
+        let mut writer = MessageWriter::new(&mut buffer, FMT, &placeholders);
+        writer.append(&i128::MIN);
+        writer.append(&Address::new_from_array([255; 32]));
+        writer.append(long.as_str());
+        let message = writer.finish();
+
+        assert_eq!(message.len(), capacity);
+        assert!(message.starts_with(b"[-170141183460469231731687303715884105728|JEKN"));
+        assert!(message.ends_with(&[b"z".repeat(MAX_UNSIZED_LEN).as_slice(), b"]"].concat()));
+    }
+}
@@ -22,3 +22,18 @@ pub fn extract_type(ty: &Type) -> Result<TypeNode, syn::Error> {
         )))
     }
 }
+
+/// Returns the fixed address of the sysvar `name`, as read by the `Sysvar<T>` account type.
+pub fn sysvar_address(name: &str) -> Option<&'static str> {
+    let address = match name {
+        "Clock" => "SysvarC1ock11111111111111111111111111111111",
+        "EpochSchedule" => "SysvarEpochSchedu1e111111111111111111111111",
+        "Instructions" => "Sysvar1nstructions1111111111111111111111111",
+        "RecentBlockhashes" => "SysvarRecentB1ockHashes11111111111111111111",
+        "Rent" => "SysvarRent111111111111111111111111111111111",
+        "SlotHashes" => "SysvarS1otHashes111111111111111111111111111",
+        "StakeHistory" => "SysvarStakeHistory1111111111111111111111111",
+        _ => return None,
+    };
+    Some(address)
+}
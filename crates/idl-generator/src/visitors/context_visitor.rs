@@ -1,9 +1,12 @@
 use {
-    crate::{helpers::AttributesHelper, utils::extract_type},
+    crate::{
+        helpers::AttributesHelper,
+        utils::{extract_type, sysvar_address},
+    },
     codama::{
         CamelCaseString, DefinedTypeLinkNode, Docs, InstructionAccountNode,
         InstructionArgumentNode, InstructionNode, IsAccountSigner, KorokVisitor, Node,
-        StructFieldTypeNode, StructTypeNode, TypeNode,
+        PublicKeyValueNode, StructFieldTypeNode, StructTypeNode, TypeNode,
     },
     typhoon_syn::{Arguments, InstructionAccount},
 };
@@ -83,8 +86,12 @@ impl KorokVisitor for ContextVisitor {
             return Ok(());
         }
         let account = InstructionAccount::try_from(korok.ast)?;
+        let default_value = (account.get_ty().ident == "Sysvar")
+            .then(|| sysvar_address(&account.inner_ty.to_string()))
+            .flatten()
+            .map(|address| PublicKeyValueNode::new(address).into());
         korok.node = Some(Node::InstructionAccount(InstructionAccountNode {
-            default_value: None,
+            default_value,
             docs: Docs::from(account.docs.clone()),
             is_optional: account.meta.is_optional,
             is_signer: if account.meta.is_optional && account.meta.is_signer {
@@ -147,6 +154,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_visit_sysvar_field() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[context]
+            pub struct Introspect {
+                pub instructions: Sysvar<Instructions>,
+                pub custom: Sysvar<Custom>,
+            }
+        };
+
+        let mut korok = StructKorok::parse(&item)?;
+        let mut visitor = ContextVisitor::new();
+        korok.accept(&mut visitor)?;
+
+        let Some(Node::InstructionAccount(instructions)) = &korok.fields[0].node else {
+            panic!("Expected InstructionAccount node for instructions");
+        };
+        assert_eq!(
+            instructions.default_value,
+            Some(PublicKeyValueNode::new("Sysvar1nstructions1111111111111111111111111").into())
+        );
+
+        let Some(Node::InstructionAccount(custom)) = &korok.fields[1].node else {
+            panic!("Expected InstructionAccount node for custom");
+        };
+        assert!(custom.default_value.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_visit_field_outside_context() -> CodamaResult<()> {
         let item: Item = parse_quote! {
@@ -1,11 +1,13 @@
 use {
     base64::{prelude::BASE64_STANDARD, Engine},
     codama::{
-        AccountNode, CamelCaseString, CombineTypesVisitor, ConstantDiscriminatorNode,
-        ConstantValueNode, DefinedTypeNode, DiscriminatorNode, Docs, KorokVisitor, Node, TypeNode,
+        AccountNode, BytesTypeNode, BytesValueNode, CamelCaseString, CombineTypesVisitor,
+        ConstantDiscriminatorNode, ConstantPdaSeedNode, ConstantValueNode, DefinedTypeNode,
+        DiscriminatorNode, Docs, KorokVisitor, Node, PdaLinkNode, PdaNode, PdaSeedNode,
+        ProgramNode, StringTypeNode, StructTypeNode, TypeNode, VariablePdaSeedNode,
     },
-    typhoon_discriminator::DiscriminatorBuilder,
-    typhoon_syn::Docs as TyphoonDocs,
+    syn::{Attribute, Expr, ExprLit, ItemStruct, Lit, LitInt, Type},
+    typhoon_syn::{AccountDiscriminator, Docs as TyphoonDocs},
 };
 
 pub struct SetAccountVisitor {
@@ -35,21 +37,25 @@ impl KorokVisitor for SetAccountVisitor {
         self.visitor.visit_struct(korok)?;
 
         let Some(Node::DefinedType(DefinedTypeNode {
-            r#type: TypeNode::Struct(ty),
+            r#type: TypeNode::Struct(mut ty),
             ..
         })) = korok.node.take()
         else {
             return Ok(());
         };
+        document_bump_field(korok.ast, &mut ty);
 
-        let dis = DiscriminatorBuilder::new(&korok.ast.ident.to_string()).build();
+        let dis = AccountDiscriminator::try_from(korok.ast.attrs.as_slice())?
+            .build(&korok.ast.ident.to_string());
+        let name = CamelCaseString::new(korok.ast.ident.to_string());
+        let pda = parse_pda_node(&name, korok.ast, &ty);
 
-        korok.node = Some(Node::Account(AccountNode {
-            name: CamelCaseString::new(korok.ast.ident.to_string()),
+        let account = AccountNode {
+            name,
             size: None,
             docs: Docs::from(TyphoonDocs::from(korok.ast.attrs.as_slice()).into_vec()),
             data: codama::NestedTypeNode::Value(ty),
-            pda: None,
+            pda: pda.as_ref().map(|pda| PdaLinkNode::new(pda.name.clone())),
             discriminators: vec![DiscriminatorNode::Constant(ConstantDiscriminatorNode::new(
                 ConstantValueNode::bytes(
                     codama::BytesEncoding::Base64,
@@ -57,12 +63,153 @@ impl KorokVisitor for SetAccountVisitor {
                 ),
                 0,
             ))],
-        }));
+        };
+
+        korok.node = Some(match pda {
+            Some(pda) => Node::Program(ProgramNode {
+                accounts: vec![account],
+                pdas: vec![pda],
+                ..ProgramNode::default()
+            }),
+            None => Node::Account(account),
+        });
 
         Ok(())
     }
 }
 
+/// Builds the PDA of an account from its `#[seeds(..)]` attribute and `#[key]` fields.
+///
+/// Returns `None` if the account is not seeded or if one of its constant seeds is not a
+/// byte string literal, since its value cannot be known without evaluating the program.
+fn parse_pda_node(
+    name: &CamelCaseString,
+    item: &ItemStruct,
+    ty: &StructTypeNode,
+) -> Option<PdaNode> {
+    let seeds_attr = item.attrs.iter().find(|attr| attr.path().is_ident("seeds"));
+
+    let mut keys = Vec::new();
+    for field in item.fields.iter() {
+        let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("key")) else {
+            continue;
+        };
+        let field_name = CamelCaseString::new(field.ident.as_ref()?.to_string());
+        let field_ty = ty.fields.iter().find(|f| f.name == field_name)?;
+        keys.push((
+            key_order(attr),
+            VariablePdaSeedNode::new(field_name, seed_type(&field.ty, &field_ty.r#type)),
+        ));
+    }
+    keys.sort_by_key(|(order, _)| *order);
+
+    if keys.is_empty() && seeds_attr.is_none() {
+        return None;
+    }
+
+    let mut prefix = Some(Some(item.ident.to_string().to_lowercase().into_bytes()));
+    let mut constants = Vec::new();
+    if let Some(attr) = seeds_attr {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_prefix") {
+                prefix = None;
+            } else if meta.path.is_ident("prefix") {
+                prefix = Some(byte_string(&meta.value()?.parse()?));
+            } else if meta.path.is_ident("constant") {
+                constants.push(byte_string(&meta.value()?.parse()?));
+            }
+            Ok(())
+        })
+        .ok()?;
+    }
+
+    let constants = prefix
+        .into_iter()
+        .chain(constants)
+        .collect::<Option<Vec<_>>>()?;
+
+    let seeds = constants
+        .into_iter()
+        .map(|bytes| {
+            let value = match String::from_utf8(bytes) {
+                Ok(value) => BytesValueNode::utf8(value),
+                Err(err) => BytesValueNode::base16(
+                    err.as_bytes()
+                        .iter()
+                        .map(|b| format!("{b:02x}"))
+                        .collect::<String>(),
+                ),
+            };
+            PdaSeedNode::Constant(ConstantPdaSeedNode::new(BytesTypeNode::new(), value))
+        })
+        .chain(keys.into_iter().map(|(_, key)| PdaSeedNode::Variable(key)))
+        .collect();
+
+    Some(PdaNode::new(name.clone(), seeds))
+}
+
+/// Documents the `#[bump]` field holding the canonical bump of the account PDA.
+fn document_bump_field(item: &ItemStruct, ty: &mut StructTypeNode) {
+    let Some(ident) = item
+        .fields
+        .iter()
+        .find(|f| f.attrs.iter().any(|attr| attr.path().is_ident("bump")))
+        .and_then(|f| f.ident.as_ref())
+    else {
+        return;
+    };
+
+    let name = CamelCaseString::new(ident.to_string());
+    if let Some(field) = ty.fields.iter_mut().find(|f| f.name == name) {
+        field
+            .docs
+            .push("Canonical bump of the account PDA, stored on `init`.");
+    }
+}
+
+/// Returns the IDL type of the bytes a `#[key]` field of type `ty` contributes to the seeds.
+///
+/// Addresses, integers and booleans are encoded like the field, `FixedStr` without its
+/// trailing zeros, and other types by their own `SeedKey` impl, described as raw bytes.
+fn seed_type(ty: &Type, field_ty: &TypeNode) -> TypeNode {
+    let Type::Path(path) = ty else {
+        return BytesTypeNode::new().into();
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return BytesTypeNode::new().into();
+    };
+
+    match segment.ident.to_string().as_str() {
+        "Address" | "Pubkey" | "bool" | "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16"
+        | "i32" | "i64" | "i128" => field_ty.clone(),
+        "FixedStr" => StringTypeNode::utf8().into(),
+        _ => BytesTypeNode::new().into(),
+    }
+}
+
+/// Returns the `order = n` argument of a `#[key]` attribute.
+fn key_order(attr: &Attribute) -> Option<u32> {
+    let mut order = None;
+    let _ = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("order") {
+            order = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+        }
+        Ok(())
+    });
+    order
+}
+
+/// Returns the bytes of a byte string literal seed.
+fn byte_string(expr: &Expr) -> Option<Vec<u8>> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::ByteStr(lit),
+            ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -100,4 +247,104 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_visit_invalid_discriminator() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+            #[repr(C)]
+            #[discriminator(compact = 3)]
+            pub struct Counter {
+                pub count: u64,
+            }
+        };
+
+        let mut korok = StructKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        let err = korok.accept(&mut SetAccountVisitor::new()).unwrap_err();
+
+        assert_eq!(err.to_string(), "`compact` must be 1 or 2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_seeded_struct() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+            #[repr(C)]
+            #[seeds(prefix = b"vault", constant = b"v1")]
+            pub struct Vault {
+                #[key]
+                pub authority: Address,
+                pub amount: u64,
+                #[bump]
+                pub bump: u8,
+            }
+        };
+
+        let mut korok = StructKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        korok.accept(&mut SetAccountVisitor::new())?;
+
+        let Some(Node::Program(program)) = korok.node else {
+            panic!("Expected Program node");
+        };
+
+        assert_eq!(program.accounts[0].pda, Some(PdaLinkNode::new("vault")));
+        assert_eq!(
+            program.pdas,
+            vec![PdaNode::new(
+                "vault",
+                vec![
+                    ConstantPdaSeedNode::new(BytesTypeNode::new(), BytesValueNode::utf8("vault"))
+                        .into(),
+                    ConstantPdaSeedNode::new(BytesTypeNode::new(), BytesValueNode::utf8("v1"))
+                        .into(),
+                    VariablePdaSeedNode::new("authority", codama::PublicKeyTypeNode::new()).into(),
+                ],
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_seed_types() -> CodamaResult<()> {
+        let item: Item = parse_quote! {
+            #[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+            #[repr(C)]
+            #[seeds(no_prefix)]
+            pub struct Profile {
+                #[key]
+                pub name: FixedStr<16>,
+                #[key]
+                pub tag: [u8; 4],
+                #[key]
+                pub epoch: Epoch,
+                #[key]
+                pub id: u64,
+            }
+        };
+
+        let mut korok = StructKorok::parse(&item)?;
+        korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        korok.accept(&mut SetAccountVisitor::new())?;
+
+        let Some(Node::Program(program)) = korok.node else {
+            panic!("Expected Program node");
+        };
+
+        assert_eq!(
+            program.pdas[0].seeds,
+            vec![
+                VariablePdaSeedNode::new("name", StringTypeNode::utf8()).into(),
+                VariablePdaSeedNode::new("tag", BytesTypeNode::new()).into(),
+                VariablePdaSeedNode::new("epoch", BytesTypeNode::new()).into(),
+                VariablePdaSeedNode::new("id", codama::NumberTypeNode::le(codama::U64)).into(),
+            ]
+        );
+
+        Ok(())
+    }
 }
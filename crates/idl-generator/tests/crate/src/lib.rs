@@ -100,3 +100,11 @@ pub struct AnotherStruct {
 pub struct ExcludedType {
     pub more_data: u32,
 }
+
+#[derive(NoUninit, AnyBitPattern, AccountState, Copy, Clone)]
+#[repr(C)]
+#[seeds(prefix = b"vault")]
+pub struct Vault {
+    #[key]
+    pub authority: Address,
+}
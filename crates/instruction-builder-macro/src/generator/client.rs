@@ -89,6 +89,7 @@ fn generate_accounts(accounts: &[InstructionAccount]) -> (Vec<TokenStream>, Vec<
 
 impl Generator for ClientGenerator {
     fn generate_token(
+        _program_name: &str,
         instructions: &hashbrown::HashMap<usize, Instruction>,
         context: &hashbrown::HashMap<String, Context>,
         extra_token: TokenStream,
@@ -11,21 +11,12 @@ pub struct CpiGenerator;
 
 fn generate_ctx(ctxs: &hashbrown::HashMap<String, Context>) -> TokenStream {
     let tokens = ctxs.values().map(|ctx| {
-        let name = &ctx.name;
-        let ctx_name = format_ident!("{}Context", name);
-        let (args_field, args_assign) = ctx
-            .arguments
-            .as_ref()
-            .map(|args| {
-                let arg_ty = match args {
-                    Arguments::Values(_) => &format_ident!("{name}Args"),
-                    Arguments::Struct(ident) => ident,
-                };
-                generate_arg((&format_ident!("args"), &parse_quote!(#arg_ty)))
-            })
+        let ctx_name = format_ident!("{}Context", ctx.name);
+        let (args_field, args_assign) = ctx_args_ty(ctx)
+            .map(|arg_ty| generate_arg((&format_ident!("args"), &parse_quote!(#arg_ty))))
             .unzip();
-        let (acc_fields, metas, infos) = generate_accounts(&ctx.accounts);
-        let arg_writer = args_assign.map(|el| quote!(writer.write_bytes(#el)?;));
+        let (acc_fields, acc_pushes) = generate_accounts(&ctx.accounts);
+        let arg_writer = args_assign.map(|el| quote!(builder.data(#el)?;));
         let has_optional = ctx.accounts.iter().any(|acc| acc.meta.is_optional);
         let program_field = if has_optional {
             Some(quote!(program: &'a AccountView,))
@@ -43,20 +34,10 @@ fn generate_ctx(ctxs: &hashbrown::HashMap<String, Context>) -> TokenStream {
                 fn append(
                     &self,
                     #program_field
-                    writer: &mut bytes::MaybeUninitWriter,
-                    metas: &mut [core::mem::MaybeUninit<instruction::InstructionAccount<'a>>],
-                    infos: &mut [core::mem::MaybeUninit<&'a AccountView>],
+                    builder: &mut bytes::CpiBuilder<'a, '_>,
                 ) -> ProgramResult {
                     #arg_writer
-
-                    for ((meta_dest, info_dest), (meta_src, info_src)) in metas
-                        .iter_mut()
-                        .zip(infos.iter_mut())
-                        .zip(core::iter::zip([#(#metas),*], [#(#infos),*]))
-                    {
-                        meta_dest.write(meta_src);
-                        info_dest.write(info_src);
-                    }
+                    #(#acc_pushes)*
 
                     Ok(())
                 }
@@ -67,6 +48,13 @@ fn generate_ctx(ctxs: &hashbrown::HashMap<String, Context>) -> TokenStream {
     quote!(#(#tokens)*)
 }
 
+fn ctx_args_ty(ctx: &Context) -> Option<Ident> {
+    ctx.arguments.as_ref().map(|args| match args {
+        Arguments::Values(_) => format_ident!("{}Args", ctx.name),
+        Arguments::Struct(ident) => ident.clone(),
+    })
+}
+
 fn generate_arg((name, ty): (&Ident, &Type)) -> (TokenStream, TokenStream) {
     (
         quote!(pub #name: &'a #ty,),
@@ -74,13 +62,10 @@ fn generate_arg((name, ty): (&Ident, &Type)) -> (TokenStream, TokenStream) {
     )
 }
 
-fn generate_accounts(
-    accounts: &[InstructionAccount],
-) -> (Vec<TokenStream>, Vec<TokenStream>, Vec<TokenStream>) {
+fn generate_accounts(accounts: &[InstructionAccount]) -> (Vec<TokenStream>, Vec<TokenStream>) {
     let len = accounts.len();
     let mut account_fields = Vec::with_capacity(len);
-    let mut metas = Vec::with_capacity(len);
-    let mut infos = Vec::with_capacity(len);
+    let mut pushes = Vec::with_capacity(len);
 
     for acc in accounts {
         let name = &acc.name;
@@ -88,39 +73,33 @@ fn generate_accounts(
         let is_mutable = acc.meta.is_mutable;
         let is_signer = acc.meta.is_signer;
 
-        let field = if is_optional {
-            quote!(pub #name: Option<&'a AccountView>,)
-        } else {
-            quote!(pub #name: &'a AccountView,)
-        };
-        let meta = if is_optional {
-            quote! {
-                if let Some(#name) = self.#name {
-                    instruction::InstructionAccount::new(#name.address(), #is_mutable, #is_signer)
-                }else {
-                    instruction::InstructionAccount::new(program.address(), false, false)
-                }
-            }
-        } else {
-            quote!(instruction::InstructionAccount::new(self.#name.address(), #is_mutable, #is_signer))
-        };
-
-        let info = if is_optional {
-            quote!(self.#name.unwrap_or(program))
+        let (field, push) = if is_optional {
+            (
+                quote!(pub #name: Option<&'a AccountView>,),
+                quote! {
+                    match self.#name {
+                        Some(#name) => builder.account(#name, #is_mutable, #is_signer)?,
+                        None => builder.account(program, false, false)?,
+                    }
+                },
+            )
         } else {
-            quote!(self.#name)
+            (
+                quote!(pub #name: &'a AccountView,),
+                quote!(builder.account(self.#name, #is_mutable, #is_signer)?;),
+            )
         };
 
         account_fields.push(field);
-        metas.push(meta);
-        infos.push(info);
+        pushes.push(push);
     }
 
-    (account_fields, metas, infos)
+    (account_fields, pushes)
 }
 
 impl Generator for CpiGenerator {
     fn generate_token(
+        program_name: &str,
         instructions: &hashbrown::HashMap<usize, typhoon_syn::Instruction>,
         context: &hashbrown::HashMap<String, typhoon_syn::Context>,
         extra_token: TokenStream,
@@ -132,18 +111,6 @@ impl Generator for CpiGenerator {
             let instruction_name =
                 format_ident!("{}Cpi", ix.name.to_string().to_upper_camel_case());
             let dis = *discriminator as u8;
-            let (result_ty, return_data) = if let InstructionReturnData { ty: Some(ref ty), .. } = ix.return_data {
-                (
-                    Some(quote!(<#ty>)),
-                    quote! {
-                        bytemuck::pod_read_unaligned(
-                            &cpi::get_return_data().ok_or(ErrorCode::InvalidReturnData)?.as_slice(),
-                        )
-                    },
-                )
-            } else {
-                (None, quote!(()))
-            };
             let mut data_len = Vec::new();
             let mut accumulated_len = 0;
             let mut  has_optional = false;
@@ -155,7 +122,7 @@ impl Generator for CpiGenerator {
                         let (field, bytes) = generate_arg((arg_name, ty));
                         data_len.push(quote!(core::mem::size_of::<#ty>()));
                         fields.push(field);
-                        assigns.push(quote!(writer.write_bytes(#bytes)?;));
+                        assigns.push(quote!(builder.data(#bytes)?;));
                     }
                     InstructionArg::Context(ctx_name) => {
                         if let Some(ctx) = context.get(&ctx_name.to_string()) {
@@ -166,10 +133,11 @@ impl Generator for CpiGenerator {
 
                             let program_arg = ctx_has_optional.then(|| quote!(self.program,));
                             let ctx_struct = format_ident!("{ctx_name}Context");
-                            let acc_len = ctx.accounts.len();
-                            let new_len = accumulated_len + acc_len;
-                            assigns.push(quote!(self.#arg_name.append(#program_arg &mut writer, &mut metas[#accumulated_len..#new_len], &mut infos[#accumulated_len..#new_len])?;));
-                            accumulated_len = new_len;
+                            if let Some(arg_ty) = ctx_args_ty(ctx) {
+                                data_len.push(quote!(core::mem::size_of::<#arg_ty>()));
+                            }
+                            assigns.push(quote!(self.#arg_name.append(#program_arg &mut builder)?;));
+                            accumulated_len += ctx.accounts.len();
                             fields.push(quote!(pub #arg_name: #ctx_struct<'a>,));
                         } else {
                             let compile_error = syn::Error::new_spanned(
@@ -190,6 +158,30 @@ impl Generator for CpiGenerator {
             } else {
                 (quote!(&'a Address), None)
             };
+            let (result_ty, return_data, return_methods) = if let InstructionReturnData { ty: Some(ref ty), .. } = ix.return_data {
+                (
+                    Some(quote!(<#ty>)),
+                    quote! {
+                        let return_data = return_data::get_return_data_from(self.program #program_id_getter)?;
+                        bytemuck::try_pod_read_unaligned(return_data.as_slice())
+                            .map_err(|_| ErrorCode::InvalidReturnData.into())
+                    },
+                    Some(quote! {
+                        /// Invokes the instruction and decodes the return data set by the callee.
+                        #[inline(always)]
+                        pub fn invoke_and_get_return(&self) -> ProgramResult<#ty> {
+                            self.invoke()
+                        }
+
+                        #[inline(always)]
+                        pub fn invoke_signed_and_get_return(&self, seeds: &[CpiSigner]) -> ProgramResult<#ty> {
+                            self.invoke_signed(seeds)
+                        }
+                    }),
+                )
+            } else {
+                (None, quote!(Ok(())), None)
+            };
 
             token.extend(quote! {
                 pub struct #instruction_name<'a> {
@@ -205,27 +197,42 @@ impl Generator for CpiGenerator {
 
                     #[inline(always)]
                     pub fn invoke_signed(&self, seeds: &[CpiSigner]) -> ProgramResult #result_ty {
-                        let mut bytes = [bytes::UNINIT_BYTE; 1 #(+ #data_len)*];
+                        let mut data = [bytes::UNINIT_BYTE; 1 #(+ #data_len)*];
                         let mut metas = [bytes::UNINIT_INS_ACC; #accumulated_len];
                         let mut infos = [bytes::UNINIT_ACC_VIEW; #accumulated_len];
-                        let mut writer = bytes::MaybeUninitWriter::new(&mut bytes, 0);
-                        writer.write_bytes(&[#dis])?;
+                        let mut builder = bytes::CpiBuilder::new(&mut data, &mut metas, &mut infos);
+                        builder.data(&[#dis])?;
 
                         #(#assigns)*
 
-                        let instruction = instruction::InstructionView {
-                            program_id:  self.program #program_id_getter,
-                            data: writer.initialized(),
-                            accounts: unsafe { core::slice::from_raw_parts(metas.as_ptr() as *const _, #accumulated_len) }
-                        };
+                        builder.invoke_signed::<#accumulated_len>(self.program #program_id_getter, seeds)?;
+
+                        #return_data
+                    }
 
-                        cpi::invoke_signed(
-                            &instruction,
-                            unsafe { &*(infos.as_ptr() as *const [&AccountView; #accumulated_len]) },
-                            seeds
-                        )?;
+                    #return_methods
 
-                        Ok(#return_data)
+                    /// Invokes the instruction, converting the callee's custom errors with `map`.
+                    ///
+                    /// Returned errors are tagged with the callee program name for `log_error`.
+                    #[inline(always)]
+                    pub fn invoke_mapped<C, E>(&self, map: impl FnOnce(C) -> E) -> ProgramResult #result_ty
+                    where
+                        C: TryFrom<u32>,
+                        E: Into<Error>,
+                    {
+                        self.invoke_signed_mapped(&[], map)
+                    }
+
+                    #[inline(always)]
+                    pub fn invoke_signed_mapped<C, E>(&self, seeds: &[CpiSigner], map: impl FnOnce(C) -> E) -> ProgramResult #result_ty
+                    where
+                        C: TryFrom<u32>,
+                        E: Into<Error>,
+                    {
+                        self.invoke_signed(seeds)
+                            .map_custom_err(map)
+                            .trace_program(#program_name)
                     }
                 }
             });
@@ -10,6 +10,7 @@ use {
 
 pub trait Generator {
     fn generate_token(
+        program_name: &str,
         instructions: &HashMap<usize, Instruction>,
         context: &HashMap<String, Context>,
         extra_token: TokenStream,
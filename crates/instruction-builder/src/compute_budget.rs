@@ -0,0 +1,138 @@
+use {alloc::vec::Vec, solana_address::Address, solana_instruction::Instruction};
+
+/// Instructions of the Compute Budget program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeBudgetInstruction {
+    /// Requests a heap frame of the given size in bytes, a multiple of 1024.
+    RequestHeapFrame(u32),
+    /// Sets the compute unit limit of the transaction.
+    SetComputeUnitLimit(u32),
+    /// Sets the compute unit price of the transaction, in micro-lamports.
+    SetComputeUnitPrice(u64),
+    /// Sets the maximum size in bytes of the accounts loaded by the transaction.
+    SetLoadedAccountsDataSizeLimit(u32),
+}
+
+impl ComputeBudgetInstruction {
+    /// The address of the Compute Budget program.
+    pub const PROGRAM_ID: Address = Address::new_from_array([
+        3, 6, 70, 111, 229, 33, 23, 50, 255, 236, 173, 186, 114, 195, 155, 231, 188, 140, 229, 187,
+        197, 247, 18, 107, 44, 67, 155, 58, 64, 0, 0, 0,
+    ]);
+
+    #[inline(always)]
+    pub fn into_instruction(self) -> Instruction {
+        let mut data = Vec::with_capacity(9);
+
+        match self {
+            Self::RequestHeapFrame(bytes) => {
+                data.push(1);
+                data.extend_from_slice(&bytes.to_le_bytes());
+            }
+            Self::SetComputeUnitLimit(units) => {
+                data.push(2);
+                data.extend_from_slice(&units.to_le_bytes());
+            }
+            Self::SetComputeUnitPrice(micro_lamports) => {
+                data.push(3);
+                data.extend_from_slice(&micro_lamports.to_le_bytes());
+            }
+            Self::SetLoadedAccountsDataSizeLimit(bytes) => {
+                data.push(4);
+                data.extend_from_slice(&bytes.to_le_bytes());
+            }
+        }
+
+        Instruction {
+            program_id: Self::PROGRAM_ID,
+            accounts: Vec::new(),
+            data,
+        }
+    }
+}
+
+/// Builder of the Compute Budget instructions to prepend to a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    heap_frame: Option<u32>,
+    unit_limit: Option<u32>,
+    unit_price: Option<u64>,
+    loaded_accounts_data_size_limit: Option<u32>,
+}
+
+impl ComputeBudget {
+    /// Requests a heap frame of `bytes`, a multiple of 1024.
+    #[inline(always)]
+    pub fn heap_frame(mut self, bytes: u32) -> Self {
+        self.heap_frame = Some(bytes);
+        self
+    }
+
+    /// Sets the compute unit limit to `units`.
+    #[inline(always)]
+    pub fn unit_limit(mut self, units: u32) -> Self {
+        self.unit_limit = Some(units);
+        self
+    }
+
+    /// Sets the compute unit price to `micro_lamports`.
+    #[inline(always)]
+    pub fn unit_price(mut self, micro_lamports: u64) -> Self {
+        self.unit_price = Some(micro_lamports);
+        self
+    }
+
+    /// Sets the maximum size of the loaded accounts to `bytes`.
+    #[inline(always)]
+    pub fn loaded_accounts_data_size_limit(mut self, bytes: u32) -> Self {
+        self.loaded_accounts_data_size_limit = Some(bytes);
+        self
+    }
+
+    /// Returns the instructions setting the configured values.
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        [
+            self.heap_frame
+                .map(ComputeBudgetInstruction::RequestHeapFrame),
+            self.unit_limit
+                .map(ComputeBudgetInstruction::SetComputeUnitLimit),
+            self.unit_price
+                .map(ComputeBudgetInstruction::SetComputeUnitPrice),
+            self.loaded_accounts_data_size_limit
+                .map(ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit),
+        ]
+        .into_iter()
+        .flatten()
+        .map(ComputeBudgetInstruction::into_instruction)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_instructions() {
+        let ixs = ComputeBudget::default()
+            .unit_price(5)
+            .unit_limit(200_000)
+            .into_instructions();
+
+        assert_eq!(ixs.len(), 2);
+        assert!(ixs
+            .iter()
+            .all(|ix| ix.program_id == ComputeBudgetInstruction::PROGRAM_ID));
+        assert_eq!(ixs[0].data, [2, 64, 13, 3, 0]);
+        assert_eq!(ixs[1].data, [3, 5, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(ComputeBudget::default().into_instructions().is_empty());
+    }
+
+    #[test]
+    fn program_id() {
+        assert_eq!(
+            ComputeBudgetInstruction::PROGRAM_ID,
+            Address::from_str_const("ComputeBudget111111111111111111111111111111")
+        );
+    }
+}
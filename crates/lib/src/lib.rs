@@ -18,6 +18,18 @@ pub mod bytes {
     pub use typhoon_utility::bytes::*;
 }
 
+pub mod introspection {
+    pub use typhoon_utility::introspection::*;
+}
+
+pub mod precompiles {
+    pub use typhoon_utility::precompiles::*;
+}
+
+pub mod return_data {
+    pub use typhoon_utility::return_data::*;
+}
+
 pub mod instruction {
     pub use pinocchio::instruction::{InstructionAccount, InstructionView};
 }
@@ -39,7 +51,13 @@ pub mod prelude {
     #[cfg(feature = "logging")]
     pub use typhoon_errors::{log_error, LogError};
     pub use {
-        super::{bytes, find_program_address_const, instruction, lib::*, macros::*, ProgramResult},
+        super::{
+            bytes, find_program_address_const, instruction,
+            introspection::{self, InstructionsExt, IntrospectedInstructionExt},
+            lib::*,
+            macros::*,
+            precompiles, return_data, ProgramResult,
+        },
         pinocchio::{
             self,
             address::{self, address_eq, declare_id, MAX_SEEDS},
@@ -49,7 +67,7 @@ pub mod prelude {
             hint,
             instruction::seeds,
             no_allocator, nostd_panic_handler, program_entrypoint,
-            sysvars::{clock::Clock, fees::Fees, rent::Rent, Sysvar},
+            sysvars::{self, clock::Clock, fees::Fees, rent::Rent, Sysvar as _},
             AccountView, Address,
         },
     };
@@ -1,2 +1,38 @@
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    Expr, Ident, Token,
+};
+
 #[derive(Clone, Debug)]
 pub struct ConstraintInit;
+
+/// `init::with_seed = (base, seed)`, creating the account at the address derived
+/// from the `base` account and the `seed` string.
+#[derive(Clone)]
+pub struct ConstraintInitWithSeed {
+    pub base: Ident,
+    pub seed: Expr,
+}
+
+impl Parse for ConstraintInitWithSeed {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![::]>()?;
+        let name = input.parse::<Ident>()?;
+        if name != "with_seed" {
+            return Err(syn::Error::new(
+                name.span(),
+                "Invalid variant for the init constraint.",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let base = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let seed = content.parse()?;
+
+        Ok(ConstraintInitWithSeed { base, seed })
+    }
+}
@@ -0,0 +1,18 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+#[derive(Clone)]
+pub struct ConstraintInitValue {
+    pub value: Expr,
+}
+
+impl Parse for ConstraintInitValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(ConstraintInitValue { value })
+    }
+}
@@ -10,17 +10,21 @@ mod bump;
 mod has_one;
 mod init;
 mod init_if_needed;
+mod init_value;
 mod mint;
+mod next_instruction;
 mod payer;
 mod program;
 mod seeded;
 mod seeds;
 mod space;
+mod stake;
 mod token;
 
 pub use {
     address::*, assert::*, associated_token::*, bump::*, has_one::*, init::*, init_if_needed::*,
-    mint::*, payer::*, program::*, seeded::*, seeds::*, space::*, token::*,
+    init_value::*, mint::*, next_instruction::*, payer::*, program::*, seeded::*, seeds::*,
+    space::*, stake::*, token::*,
 };
 
 pub const CONSTRAINT_IDENT_STR: &str = "constraint";
@@ -31,6 +35,7 @@ pub enum Constraint {
     Init(ConstraintInit),
     Payer(ConstraintPayer),
     Space(ConstraintSpace),
+    InitValue(ConstraintInitValue),
     Seeded(ConstraintSeeded),
     Seeds(ConstraintSeeds),
     Bump(ConstraintBump),
@@ -42,6 +47,9 @@ pub enum Constraint {
     InitIfNeeded(ConstraintInitIfNeeded),
     Assert(ConstraintAssert),
     Address(ConstraintAddress),
+    NextInstruction(ConstraintNextInstruction),
+    Stake(ConstraintStake),
+    InitWithSeed(ConstraintInitWithSeed),
 }
 
 impl Constraint {
@@ -50,17 +58,21 @@ impl Constraint {
             Self::Init(_) => 0,
             Self::InitIfNeeded(_) => 1,
             Self::Space(_) => 2,
-            Self::Seeded(_) => 3,
-            Self::Seeds(_) => 4,
-            Self::Bump(_) => 5,
-            Self::Program(_) => 6,
-            Self::HasOne(_) => 7,
-            Self::Token(_) => 8,
-            Self::Mint(_) => 9,
-            Self::AssociatedToken(_) => 10,
-            Self::Payer(_) => 11,
-            Self::Assert(_) => 12,
-            Self::Address(_) => 13,
+            Self::InitValue(_) => 3,
+            Self::Seeded(_) => 4,
+            Self::Seeds(_) => 5,
+            Self::Bump(_) => 6,
+            Self::Program(_) => 7,
+            Self::HasOne(_) => 8,
+            Self::Token(_) => 9,
+            Self::Mint(_) => 10,
+            Self::AssociatedToken(_) => 11,
+            Self::Payer(_) => 12,
+            Self::Assert(_) => 13,
+            Self::Address(_) => 14,
+            Self::NextInstruction(_) => 15,
+            Self::Stake(_) => 16,
+            Self::InitWithSeed(_) => 17,
         }
     }
 }
@@ -93,9 +105,15 @@ pub fn parse_constraints(input: ParseStream) -> syn::Result<Vec<Constraint>> {
     while !input.is_empty() {
         let name = input.parse::<Ident>()?.to_string();
         match name.as_str() {
+            "init" if input.peek(Token![::]) => constraints.push(Constraint::InitWithSeed(
+                ConstraintInitWithSeed::parse(input)?,
+            )),
             "init" => constraints.push(Constraint::Init(ConstraintInit)),
             "payer" => constraints.push(Constraint::Payer(ConstraintPayer::parse(input)?)),
             "space" => constraints.push(Constraint::Space(ConstraintSpace::parse(input)?)),
+            "init_value" => {
+                constraints.push(Constraint::InitValue(ConstraintInitValue::parse(input)?))
+            }
             "seeds" => constraints.push(Constraint::Seeds(ConstraintSeeds::parse(input)?)),
             "bump" => constraints.push(Constraint::Bump(ConstraintBump::parse(input)?)),
             "seeded" => constraints.push(Constraint::Seeded(ConstraintSeeded::parse(input)?)),
@@ -109,6 +127,10 @@ pub fn parse_constraints(input: ParseStream) -> syn::Result<Vec<Constraint>> {
             "init_if_needed" => constraints.push(Constraint::InitIfNeeded(ConstraintInitIfNeeded)),
             "assert" => constraints.push(Constraint::Assert(ConstraintAssert::parse(input)?)),
             "address" => constraints.push(Constraint::Address(ConstraintAddress::parse(input)?)),
+            "next_instruction" => constraints.push(Constraint::NextInstruction(
+                ConstraintNextInstruction::parse(input)?,
+            )),
+            "stake" => constraints.push(Constraint::Stake(ConstraintStake::parse(input)?)),
             _ => return Err(syn::Error::new(input.span(), "Unknown constraint.")),
         }
 
@@ -138,12 +160,15 @@ mod tests {
                 mint::decimals = args.decimals,
                 mint::authority = escrow.key(),
                 mint::freeze_authority = freeze_authority.key(),
-                init_if_needed
+                init_if_needed,
+                init::with_seed = (base, "vault"),
+                next_instruction::program = crate::ID,
+                next_instruction::discriminator = [1, 2]
             )]
         };
 
         let constraints = Constraints::try_from(attributes.as_slice()).unwrap();
 
-        assert_eq!(constraints.0.len(), 9);
+        assert_eq!(constraints.0.len(), 12);
     }
 }
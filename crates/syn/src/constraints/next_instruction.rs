@@ -0,0 +1,30 @@
+use syn::{parse::Parse, Expr, Ident, Token};
+
+#[derive(Clone)]
+pub enum ConstraintNextInstruction {
+    Program(Expr),
+    Discriminator(Expr),
+}
+
+impl Parse for ConstraintNextInstruction {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![::]>()?;
+        let name = input.parse::<Ident>()?.to_string();
+        match name.as_str() {
+            "program" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintNextInstruction::Program(input.parse()?))
+            }
+            "discriminator" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(ConstraintNextInstruction::Discriminator(input.parse()?))
+            }
+            _ => Err(syn::Error::new(
+                input.span(),
+                "Invalid variant for the next_instruction constraint.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use syn::{parse::Parse, Expr, Ident, Token};
+
+#[derive(Clone)]
+pub enum ConstraintStake {
+    Staker(Expr),
+    Withdrawer(Expr),
+}
+
+impl Parse for ConstraintStake {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![::]>()?;
+        let name = input.parse::<Ident>()?.to_string();
+        match name.as_str() {
+            "staker" => {
+                input.parse::<Token![=]>()?;
+                Ok(ConstraintStake::Staker(input.parse()?))
+            }
+            "withdrawer" => {
+                input.parse::<Token![=]>()?;
+                Ok(ConstraintStake::Withdrawer(input.parse()?))
+            }
+            _ => Err(syn::Error::new(
+                input.span(),
+                "Invalid variant for the stake constraint.",
+            )),
+        }
+    }
+}
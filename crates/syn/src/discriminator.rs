@@ -0,0 +1,190 @@
+use {
+    syn::{spanned::Spanned, Attribute, Expr, ExprArray, ExprLit, Lit, LitInt, LitStr},
+    typhoon_discriminator::DiscriminatorBuilder,
+};
+
+/// Discriminator of an account, set with `#[discriminator(..)]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountDiscriminator {
+    /// How the discriminator bytes are built.
+    pub scheme: DiscriminatorScheme,
+    /// Name hashed instead of the struct name, `#[discriminator(name = "..")]`.
+    pub name: Option<String>,
+}
+
+/// Discriminator scheme of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscriminatorScheme {
+    /// `sha256(name)[..4]` followed by the layout version and three zero bytes.
+    Hashed { layout: u8 },
+    /// Explicit bytes, `#[discriminator(bytes = [..])]`.
+    Bytes(Vec<u8>),
+    /// First 1 or 2 bytes of `sha256(name)`, `#[discriminator(compact = n)]`.
+    Compact(usize),
+    /// Anchor's `sha256("account:<Name>")[..8]`, `#[discriminator(anchor)]`.
+    Anchor,
+}
+
+impl Default for DiscriminatorScheme {
+    fn default() -> Self {
+        DiscriminatorScheme::Hashed { layout: 1 }
+    }
+}
+
+impl DiscriminatorScheme {
+    /// Returns the discriminator bytes of the account named `name`.
+    pub fn build(&self, name: &str) -> Vec<u8> {
+        let builder = DiscriminatorBuilder::new(name);
+        match self {
+            DiscriminatorScheme::Hashed { layout } => builder.layout(*layout).build().to_vec(),
+            DiscriminatorScheme::Bytes(bytes) => bytes.clone(),
+            DiscriminatorScheme::Compact(1) => builder.build_compact::<1>().to_vec(),
+            DiscriminatorScheme::Compact(_) => builder.build_compact::<2>().to_vec(),
+            DiscriminatorScheme::Anchor => builder.build_anchor().to_vec(),
+        }
+    }
+}
+
+impl AccountDiscriminator {
+    /// Returns the discriminator bytes of the account named `name`, unless
+    /// overridden with `name = ".."`.
+    pub fn build(&self, name: &str) -> Vec<u8> {
+        self.scheme.build(self.name.as_deref().unwrap_or(name))
+    }
+}
+
+impl TryFrom<&[Attribute]> for AccountDiscriminator {
+    type Error = syn::Error;
+
+    fn try_from(attrs: &[Attribute]) -> Result<Self, Self::Error> {
+        let Some(attr) = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("discriminator"))
+        else {
+            return Ok(AccountDiscriminator::default());
+        };
+
+        let mut name = None;
+        let mut scheme = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: LitStr = meta.value()?.parse()?;
+                name = Some(value.value());
+                return Ok(());
+            }
+
+            if scheme.is_some() {
+                return Err(meta.error("Only one discriminator option can be set"));
+            }
+
+            let value = if meta.path.is_ident("anchor") {
+                DiscriminatorScheme::Anchor
+            } else if meta.path.is_ident("layout") {
+                let layout: LitInt = meta.value()?.parse()?;
+                DiscriminatorScheme::Hashed {
+                    layout: layout.base10_parse()?,
+                }
+            } else if meta.path.is_ident("compact") {
+                let len: LitInt = meta.value()?.parse()?;
+                match len.base10_parse()? {
+                    len @ (1 | 2) => DiscriminatorScheme::Compact(len),
+                    _ => return Err(syn::Error::new(len.span(), "`compact` must be 1 or 2")),
+                }
+            } else if meta.path.is_ident("bytes") {
+                let array: ExprArray = meta.value()?.parse()?;
+                let bytes = array
+                    .elems
+                    .iter()
+                    .map(|elem| match elem {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Int(lit), ..
+                        }) => lit.base10_parse::<u8>(),
+                        _ => Err(syn::Error::new(elem.span(), "Expected a `u8` literal")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if bytes.is_empty() {
+                    return Err(syn::Error::new(
+                        array.span(),
+                        "The discriminator cannot be empty",
+                    ));
+                }
+                DiscriminatorScheme::Bytes(bytes)
+            } else {
+                return Err(meta.error(
+                    "Unknown `discriminator` argument, expected `bytes`, `layout`, `compact`, `anchor` or `name`",
+                ));
+            };
+
+            scheme = Some(value);
+            Ok(())
+        })?;
+
+        Ok(AccountDiscriminator {
+            scheme: scheme.unwrap_or_default(),
+            name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, syn::parse_quote};
+
+    fn parse(attr: Attribute) -> syn::Result<DiscriminatorScheme> {
+        AccountDiscriminator::try_from([attr].as_slice()).map(|discriminator| discriminator.scheme)
+    }
+
+    #[test]
+    fn test_parse_discriminator() {
+        assert_eq!(
+            AccountDiscriminator::try_from([].as_slice()).unwrap(),
+            AccountDiscriminator::default()
+        );
+        assert_eq!(
+            parse(parse_quote!(#[discriminator(layout = 3)])).unwrap(),
+            DiscriminatorScheme::Hashed { layout: 3 }
+        );
+        assert_eq!(
+            parse(parse_quote!(#[discriminator(bytes = [1, 2, 3])])).unwrap(),
+            DiscriminatorScheme::Bytes(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse(parse_quote!(#[discriminator(compact = 2)])).unwrap(),
+            DiscriminatorScheme::Compact(2)
+        );
+        assert_eq!(
+            parse(parse_quote!(#[discriminator(anchor)])).unwrap(),
+            DiscriminatorScheme::Anchor
+        );
+
+        assert!(parse(parse_quote!(#[discriminator(compact = 4)])).is_err());
+        assert!(parse(parse_quote!(#[discriminator(bytes = [])])).is_err());
+        assert!(parse(parse_quote!(#[discriminator(anchor, layout = 2)])).is_err());
+    }
+
+    #[test]
+    fn test_discriminator_name() {
+        let discriminator = AccountDiscriminator::try_from(
+            [parse_quote!(#[discriminator(name = "state", layout = 2)])].as_slice(),
+        )
+        .unwrap();
+        assert_eq!(discriminator.name.as_deref(), Some("state"));
+        assert_eq!(
+            discriminator.build("StateV2"),
+            DiscriminatorScheme::Hashed { layout: 2 }.build("state")
+        );
+    }
+
+    #[test]
+    fn test_build_discriminator() {
+        assert_eq!(
+            DiscriminatorScheme::Anchor.build("Counter"),
+            [255, 176, 4, 245, 188, 253, 124, 25]
+        );
+        assert_eq!(
+            DiscriminatorScheme::Hashed { layout: 2 }.build("state"),
+            [75, 166, 151, 53, 2, 0, 0, 0]
+        );
+        assert_eq!(DiscriminatorScheme::Compact(1).build("state"), [75]);
+    }
+}
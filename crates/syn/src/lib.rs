@@ -6,9 +6,13 @@ mod account;
 mod arguments;
 mod context;
 mod data;
+mod discriminator;
 mod doc;
 mod errors;
 mod instruction;
 mod macros;
 
-pub use {account::*, arguments::*, context::*, data::*, doc::*, errors::*, instruction::*};
+pub use {
+    account::*, arguments::*, context::*, data::*, discriminator::*, doc::*, errors::*,
+    instruction::*,
+};
@@ -2,13 +2,17 @@
 
 use {
     core::{mem::transmute, ops::Deref},
-    pinocchio::error::ProgramError,
+    pinocchio::{
+        account::{AccountView, Ref},
+        error::ProgramError,
+    },
     pinocchio_associated_token_account::ID as ATA_PROGRAM_ID,
     pinocchio_token::{
         state::{Mint as SplMint, TokenAccount as SplTokenAccount},
         ID as TOKEN_PROGRAM_ID,
     },
     solana_address::{address_eq, Address},
+    typhoon_accounts::{map_state, StateStrategy},
     typhoon_traits::{Accessor, CheckOwner, CheckProgramId, DataStrategy, Discriminator},
 };
 
@@ -68,7 +72,7 @@ impl<'a> Accessor<'a, Mint> for SplStrategy {
             return Err(ProgramError::InvalidInstructionData);
         };
         *data = rem;
-        Ok(<Self as Accessor<Mint>>::access(to_read)?)
+        <Self as Accessor<Mint>>::access(to_read)
     }
 }
 
@@ -93,7 +97,20 @@ impl<'a> Accessor<'a, TokenAccount> for SplStrategy {
             return Err(ProgramError::InvalidInstructionData);
         };
         *data = rem;
-        Ok(<Self as Accessor<TokenAccount>>::access(to_read)?)
+        <Self as Accessor<TokenAccount>>::access(to_read)
+    }
+}
+
+impl<'a, T> StateStrategy<'a, T> for SplStrategy
+where
+    T: Discriminator + 'a,
+    Self: for<'b> Accessor<'b, T, Data = &'b T>,
+{
+    type State = Ref<'a, T>;
+
+    #[inline(always)]
+    fn state(info: &'a AccountView) -> Result<Self::State, ProgramError> {
+        map_state::<T, Self, T>(info)
     }
 }
 
@@ -1,7 +1,9 @@
 use {
-    crate::{Accessor, MutAccessor},
-    bytemuck::{try_from_bytes, try_from_bytes_mut, AnyBitPattern, NoUninit},
+    crate::{Accessor, MutAccessor, Write},
+    bytemuck::{bytes_of, try_from_bytes, try_from_bytes_mut, AnyBitPattern, NoUninit},
+    core::marker::PhantomData,
     solana_program_error::ProgramError,
+    wincode::io::Writer,
 };
 
 pub struct BytemuckStrategy;
@@ -40,3 +42,118 @@ where
         try_from_bytes_mut(data).map_err(|_| ProgramError::BorshIoError)
     }
 }
+
+impl<T> Write<T> for BytemuckStrategy
+where
+    T: NoUninit,
+{
+    #[inline(always)]
+    fn write_into(mut writer: impl Writer, data: &T) -> Result<(), ProgramError> {
+        writer
+            .write(bytes_of(data))
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    #[inline(always)]
+    fn size(_data: &T) -> Result<usize, ProgramError> {
+        Ok(core::mem::size_of::<T>())
+    }
+}
+
+/// Account body made of a `H` header followed by any number of `E` entries.
+///
+/// Derefs to the header, the entries are in [`entries`](Self::entries).
+#[repr(C)]
+pub struct Trailing<H, E> {
+    pub header: H,
+    pub entries: [E],
+}
+
+impl<H, E> Trailing<H, E> {
+    /// Returns the number of entries stored in `data`, checking the length and alignment of `data`.
+    #[inline(always)]
+    fn entries_len(data: &[u8]) -> Result<usize, ProgramError> {
+        // Entries start right after the header and the body ends right after
+        // the last entry, without padding.
+        const {
+            assert!(
+                core::mem::size_of::<E>() > 0
+                    && core::mem::size_of::<H>().is_multiple_of(core::mem::align_of::<E>())
+                    && core::mem::size_of::<E>().is_multiple_of(core::mem::align_of::<H>()),
+                "Trailing layouts need a header size multiple of the entry alignment and an entry size multiple of the header alignment"
+            )
+        };
+
+        let header_len = core::mem::size_of::<H>();
+        let entry_len = core::mem::size_of::<E>();
+        let align = core::cmp::max(core::mem::align_of::<H>(), core::mem::align_of::<E>());
+        if data.len() < header_len
+            || !(data.len() - header_len).is_multiple_of(entry_len)
+            || !(data.as_ptr() as usize).is_multiple_of(align)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok((data.len() - header_len) / entry_len)
+    }
+}
+
+impl<H, E> core::ops::Deref for Trailing<H, E> {
+    type Target = H;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.header
+    }
+}
+
+impl<H, E> core::ops::DerefMut for Trailing<H, E> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.header
+    }
+}
+
+/// Strategy for [`Trailing`] layouts, a bytemuck header followed by bytemuck `E` entries.
+pub struct TrailingStrategy<E>(PhantomData<E>);
+
+impl<'a, H, E> Accessor<'a, H> for TrailingStrategy<E>
+where
+    H: AnyBitPattern,
+    E: AnyBitPattern,
+{
+    type Data = &'a Trailing<H, E>;
+
+    #[inline(always)]
+    fn access(data: &'a [u8]) -> Result<Self::Data, ProgramError> {
+        let len = Trailing::<H, E>::entries_len(data)?;
+        let ptr = core::ptr::slice_from_raw_parts(data.as_ptr() as *const E, len);
+        // SAFETY: `data` is aligned and holds exactly the header and `len` entries,
+        // which are valid for any bit pattern.
+        Ok(unsafe { &*(ptr as *const Trailing<H, E>) })
+    }
+
+    #[inline(always)]
+    fn read(data: &mut &'a [u8]) -> Result<Self::Data, ProgramError> {
+        let trailing = Self::access(data)?;
+        *data = &[];
+        Ok(trailing)
+    }
+}
+
+impl<'a, H, E> MutAccessor<'a, H> for TrailingStrategy<E>
+where
+    H: NoUninit + AnyBitPattern,
+    E: NoUninit + AnyBitPattern,
+{
+    type Data = &'a mut Trailing<H, E>;
+
+    #[inline(always)]
+    fn access_mut(data: &'a mut [u8]) -> Result<Self::Data, ProgramError> {
+        let len = Trailing::<H, E>::entries_len(data)?;
+        let ptr = core::ptr::slice_from_raw_parts_mut(data.as_mut_ptr() as *mut E, len);
+        // SAFETY: `data` is aligned and holds exactly the header and `len` entries,
+        // which are valid for any bit pattern.
+        Ok(unsafe { &mut *(ptr as *mut Trailing<H, E>) })
+    }
+}
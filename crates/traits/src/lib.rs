@@ -5,9 +5,11 @@
 #![no_std]
 
 mod account;
+mod max_size;
+mod seed_key;
 
-pub use account::*;
-use solana_address::Address;
+pub use {account::*, max_size::*, seed_key::*};
+use {solana_address::Address, solana_program_error::ProgramError};
 
 /// Trait to check whether a program ID matches an expected program.
 pub trait CheckProgramId {
@@ -26,3 +28,38 @@ pub trait Discriminator {
     /// The discriminator bytes.
     const DISCRIMINATOR: &'static [u8];
 }
+
+/// Trait for seeded accounts that can store the canonical bump of their PDA.
+///
+/// Derived by `AccountState` for accounts with seeds, from the `#[bump]` field if any.
+pub trait StoredBump {
+    /// Whether the account has a `#[bump]` field.
+    const HAS_STORED_BUMP: bool;
+
+    /// Returns the stored bump, or `None` without a `#[bump]` field.
+    fn stored_bump(&self) -> Option<u8>;
+
+    /// Writes `bump` in the `#[bump]` field of the raw account `data`, discriminator included.
+    fn write_stored_bump(data: &mut [u8], bump: u8);
+}
+
+/// Conversion from a previous layout of an account.
+///
+/// Implemented by hand for each type listed in `#[migrate_from(..)]`.
+pub trait MigrateFrom<T>: Sized {
+    /// Builds the latest layout from the `previous` one.
+    fn migrate_from(previous: &T) -> Self;
+}
+
+/// Trait for accounts that can be read from any of their known layouts.
+///
+/// Derived by `AccountState` from `#[migrate_from(..)]`.
+pub trait Migratable: Discriminator + Sized {
+    /// Returns `true` if `data` starts with the discriminator of the account or of a previous layout.
+    fn is_known_layout(data: &[u8]) -> bool;
+
+    /// Converts the raw account `data`, discriminator included, to the latest layout.
+    ///
+    /// Returns `None` if `data` already uses the latest layout.
+    fn migrate(data: &[u8]) -> Result<Option<Self>, ProgramError>;
+}
@@ -0,0 +1,30 @@
+use solana_address::Address;
+
+/// Trait for values with a bounded serialized size.
+///
+/// Used by `AccountState` to compute the `SPACE` of Borsh and wincode accounts.
+/// `Vec` and `String` fields are sized with `#[max_len(..)]` instead.
+pub trait MaxSize {
+    /// Maximum number of bytes of the serialized value.
+    const MAX_SIZE: usize;
+}
+
+macro_rules! impl_max_size {
+    ($($ty:ty),*) => {
+        $(
+            impl MaxSize for $ty {
+                const MAX_SIZE: usize = core::mem::size_of::<$ty>();
+            }
+        )*
+    };
+}
+
+impl_max_size!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, bool, Address);
+
+impl<T: MaxSize, const N: usize> MaxSize for [T; N] {
+    const MAX_SIZE: usize = N * T::MAX_SIZE;
+}
+
+impl<T: MaxSize> MaxSize for Option<T> {
+    const MAX_SIZE: usize = 1 + T::MAX_SIZE;
+}
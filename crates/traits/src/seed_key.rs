@@ -0,0 +1,137 @@
+use solana_address::{Address, MAX_SEED_LEN};
+
+/// Trait for values usable as a `#[key]` field of an account.
+pub trait SeedKey {
+    /// Maximum length of the seed in bytes.
+    const MAX_LEN: usize;
+
+    /// Fails to evaluate when [`Self::MAX_LEN`] exceeds `MAX_SEED_LEN`.
+    ///
+    /// Evaluated by `#[derive(AccountState)]` for every `#[key]` field.
+    #[doc(hidden)]
+    const ASSERT_MAX_LEN: () = assert!(
+        Self::MAX_LEN <= MAX_SEED_LEN,
+        "`#[key]` seeds cannot be longer than `MAX_SEED_LEN` (32 bytes)"
+    );
+
+    /// Seed representation of the value.
+    type Bytes<'a>: AsRef<[u8]>
+    where
+        Self: 'a;
+
+    /// Returns the bytes used as a PDA seed.
+    fn seed_bytes(&self) -> Self::Bytes<'_>;
+}
+
+impl SeedKey for Address {
+    const MAX_LEN: usize = 32;
+
+    type Bytes<'a> = &'a [u8];
+
+    #[inline(always)]
+    fn seed_bytes(&self) -> Self::Bytes<'_> {
+        self.as_ref()
+    }
+}
+
+macro_rules! impl_seed_key_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl SeedKey for $ty {
+                const MAX_LEN: usize = core::mem::size_of::<$ty>();
+
+                type Bytes<'a> = [u8; core::mem::size_of::<$ty>()];
+
+                #[inline(always)]
+                fn seed_bytes(&self) -> Self::Bytes<'_> {
+                    self.to_le_bytes()
+                }
+            }
+        )*
+    };
+}
+
+impl_seed_key_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl SeedKey for bool {
+    const MAX_LEN: usize = 1;
+
+    type Bytes<'a> = [u8; 1];
+
+    #[inline(always)]
+    fn seed_bytes(&self) -> Self::Bytes<'_> {
+        [*self as u8]
+    }
+}
+
+impl<const N: usize> SeedKey for [u8; N] {
+    const MAX_LEN: usize = N;
+
+    type Bytes<'a> = &'a [u8];
+
+    #[inline(always)]
+    fn seed_bytes(&self) -> Self::Bytes<'_> {
+        self
+    }
+}
+
+/// Fixed capacity string stored as zero padded UTF-8 bytes.
+///
+/// Used as a key, the trailing zero padding is not part of the seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FixedStr<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedStr<N> {
+    /// Creates a string from `value`, returning `None` if it is longer than `N` bytes.
+    pub const fn new(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() > N {
+            return None;
+        }
+
+        let mut buffer = [0; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            buffer[i] = bytes[i];
+            i += 1;
+        }
+        Some(FixedStr(buffer))
+    }
+
+    /// Returns the bytes without the trailing zero padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.0.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+
+    /// Returns the string without the trailing zero padding.
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(self.as_bytes()).ok()
+    }
+}
+
+impl<const N: usize> Default for FixedStr<N> {
+    fn default() -> Self {
+        FixedStr([0; N])
+    }
+}
+
+impl<const N: usize> SeedKey for FixedStr<N> {
+    const MAX_LEN: usize = N;
+
+    type Bytes<'a> = &'a [u8];
+
+    #[inline(always)]
+    fn seed_bytes(&self) -> Self::Bytes<'_> {
+        self.as_bytes()
+    }
+}
+
+// SAFETY: `FixedStr` is `#[repr(transparent)]` over `[u8; N]`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for FixedStr<N> {}
+
+// SAFETY: `FixedStr` is `#[repr(transparent)]` over `[u8; N]`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for FixedStr<N> {}
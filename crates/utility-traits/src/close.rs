@@ -1,4 +1,10 @@
-use {pinocchio::error::ProgramError, typhoon_accounts::WritableAccount, typhoon_errors::Error};
+use {
+    pinocchio::error::ProgramError,
+    typhoon_accounts::{
+        is_closed_account, Mut, UncheckedAccount, WritableAccount, CLOSED_ACCOUNT_DISCRIMINATOR,
+    },
+    typhoon_errors::Error,
+};
 
 pub trait CloseAccount: WritableAccount {
     #[inline(always)]
@@ -16,6 +22,132 @@ pub trait CloseAccount: WritableAccount {
         self.assign(&pinocchio_system::ID);
         self.resize(0)
     }
+
+    /// Closes the account like [`close`](Self::close), but keeps it owned by the program
+    /// with only the [`CLOSED_ACCOUNT_DISCRIMINATOR`] as data.
+    ///
+    /// If the account is funded again in the same transaction, `Account<T>` rejects it
+    /// with `ErrorCode::AccountClosed` and [`ClosedAccount::force_defund`] drains it.
+    #[inline(always)]
+    fn close_with_sentinel(&self, destination: &impl WritableAccount) -> Result<(), Error> {
+        let dest_lamports = destination.lamports();
+        let source_lamports = self.lamports();
+
+        destination.set_lamports(
+            dest_lamports
+                .checked_add(source_lamports)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+        self.set_lamports(0);
+
+        // Clear the data so that nothing of the account is left behind if it is revived.
+        self.raw_mut_data()?.fill(0);
+        self.resize(CLOSED_ACCOUNT_DISCRIMINATOR.len())?;
+        self.raw_mut_data()?
+            .copy_from_slice(&CLOSED_ACCOUNT_DISCRIMINATOR);
+
+        Ok(())
+    }
 }
 
 impl<T: WritableAccount> CloseAccount for T {}
+
+/// Cleans up accounts closed with [`CloseAccount::close_with_sentinel`] and funded again
+/// in the same transaction.
+pub trait ClosedAccount: WritableAccount {
+    /// Sends every lamport of the closed account to `destination`, failing with
+    /// `InvalidAccountData` if the account does not hold the closed sentinel.
+    #[inline(always)]
+    fn force_defund(&self, destination: &impl WritableAccount) -> Result<(), Error> {
+        if !is_closed_account(self.as_ref()) {
+            return Err(ProgramError::InvalidAccountData.into());
+        }
+
+        let dest_lamports = destination.lamports();
+        destination.set_lamports(
+            dest_lamports
+                .checked_add(self.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+        self.set_lamports(0);
+
+        Ok(())
+    }
+}
+
+impl ClosedAccount for Mut<UncheckedAccount<'_>> {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pinocchio::{
+            account::{RuntimeAccount, NOT_BORROWED},
+            AccountView,
+        },
+        typhoon_accounts::FromAccountInfo,
+    };
+
+    /// A runtime account followed by its data, as laid out by the loader.
+    #[repr(C)]
+    struct RawAccount<const N: usize> {
+        account: RuntimeAccount,
+        data: [u8; N],
+    }
+
+    impl<const N: usize> RawAccount<N> {
+        fn new(lamports: u64, data: [u8; N]) -> Self {
+            RawAccount {
+                account: RuntimeAccount {
+                    borrow_state: NOT_BORROWED,
+                    is_writable: 1,
+                    lamports,
+                    data_len: N as u64,
+                    ..RuntimeAccount::default()
+                },
+                data,
+            }
+        }
+
+        fn view(&mut self) -> AccountView {
+            unsafe { AccountView::new_unchecked(&mut self.account) }
+        }
+    }
+
+    fn force_defund(closed: &AccountView, destination: &AccountView) -> Result<(), ProgramError> {
+        let defund = || {
+            let closed = Mut::<UncheckedAccount>::try_from_info(closed)?;
+            closed.force_defund(&Mut::<UncheckedAccount>::try_from_info(destination)?)
+        };
+        defund().map_err(ProgramError::from)
+    }
+
+    #[test]
+    fn force_defund_drains_closed_account() {
+        let mut closed = RawAccount::new(5, CLOSED_ACCOUNT_DISCRIMINATOR);
+        let mut destination = RawAccount::new(10, []);
+        let (closed, destination) = (closed.view(), destination.view());
+
+        assert_eq!(force_defund(&closed, &destination), Ok(()));
+        assert_eq!(closed.lamports(), 0);
+        assert_eq!(destination.lamports(), 15);
+    }
+
+    #[test]
+    fn force_defund_rejects_open_account() {
+        let mut destination = RawAccount::new(10, []);
+        let destination = destination.view();
+
+        let mut open = RawAccount::new(5, [255, 255, 255, 255, 255, 255, 255, 0]);
+        let mut longer = RawAccount::new(5, [255; 9]);
+        let mut empty = RawAccount::new(5, []);
+        for account in [open.view(), longer.view(), empty.view()] {
+            assert_eq!(
+                force_defund(&account, &destination),
+                Err(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(account.lamports(), 5);
+        }
+        assert_eq!(destination.lamports(), 10);
+    }
+}
@@ -5,8 +5,10 @@ use {
         UncheckedAccount, WritableAccount,
     },
     typhoon_errors::Error,
-    typhoon_traits::Discriminator,
-    typhoon_utility::create_account_with_minimum_balance_signed,
+    typhoon_traits::{DataStrategy, Discriminator, Write},
+    typhoon_utility::{
+        create_account_with_minimum_balance_signed, create_account_with_seed_minimum_balance_signed,
+    },
 };
 
 pub trait CreateAccountCpi<'a, T>
@@ -35,21 +37,88 @@ where
             seeds.unwrap_or_default(),
         )?;
 
+        Ok(write_discriminator::<Self::D, T>(info))
+    }
+
+    /// Creates the account at the address derived from `base` and `seed` like
+    /// [`create`](Self::create), `base` signing instead of the account.
+    ///
+    /// PDA `base` accounts must provide their signer seeds via `seeds`, the
+    /// `init::with_seed` constraint only accepts a `Signer` base.
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn create_with_seed(
+        self,
+        rent: &Rent,
+        payer: &impl WritableAccount,
+        base: &impl ReadableAccount,
+        seed: &str,
+        owner: &Address,
+        space: usize,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<Mut<T>, Error> {
+        let info = self.into();
+        create_account_with_seed_minimum_balance_signed(
+            info,
+            base.as_ref(),
+            seed,
+            space,
+            owner,
+            payer.as_ref(),
+            rent,
+            seeds.unwrap_or_default(),
+        )?;
+
+        Ok(write_discriminator::<Self::D, T>(info))
+    }
+
+    /// Creates the account like [`create`](Self::create) and serializes `value`
+    /// after the discriminator.
+    #[inline(always)]
+    fn create_with(
+        self,
+        rent: &Rent,
+        payer: &impl WritableAccount,
+        owner: &Address,
+        space: usize,
+        seeds: Option<&[cpi::Signer]>,
+        value: &Self::D,
+    ) -> Result<Mut<T>, Error>
+    where
+        Self::D: DataStrategy,
+        <Self::D as DataStrategy>::Strategy: Write<Self::D>,
+    {
+        let account = self.create(rent, payer, owner, space, seeds)?;
+
         {
-            let data = info.data_ptr();
-            unsafe {
-                core::ptr::copy_nonoverlapping(
-                    Self::D::DISCRIMINATOR.as_ptr(),
-                    data,
-                    Self::D::DISCRIMINATOR.len(),
-                );
-            }
+            let mut data = account.raw_mut_data()?;
+            <<Self::D as DataStrategy>::Strategy as Write<Self::D>>::write_into(
+                &mut data[Self::D::DISCRIMINATOR.len()..],
+                value,
+            )?;
         }
 
-        Ok(Mut::from_raw_info(info))
+        Ok(account)
     }
 }
 
+#[inline(always)]
+fn write_discriminator<'a, D, T>(info: &'a AccountView) -> Mut<T>
+where
+    D: Discriminator,
+    T: ReadableAccount + FromRaw<'a>,
+{
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            D::DISCRIMINATOR.as_ptr(),
+            info.data_ptr(),
+            D::DISCRIMINATOR.len(),
+        );
+    }
+
+    Mut::from_raw_info(info)
+}
+
 macro_rules! impl_trait {
     ($origin: ty) => {
         impl<'a, T, C> CreateAccountCpi<'a, Signer<'a, Account<'a, T>, C>> for $origin
@@ -1,9 +1,11 @@
 use {
-    pinocchio::error::ProgramError,
+    pinocchio::{error::ProgramError, sysvars::rent::Rent},
     typhoon_accounts::{
-        Mut, Signer, SignerAccount, SignerCheck, SystemAccount, UncheckedAccount, WritableAccount,
+        Account, Mut, Signer, SignerAccount, SignerCheck, SystemAccount, UncheckedAccount,
+        WritableAccount,
     },
     typhoon_errors::Error,
+    typhoon_traits::Discriminator,
 };
 
 pub trait LamportsChecked: WritableAccount + SignerAccount {
@@ -44,3 +46,50 @@ pub trait LamportsChecked: WritableAccount + SignerAccount {
 
 impl<C: SignerCheck> LamportsChecked for Mut<Signer<'_, SystemAccount<'_>, C>> {}
 impl<C: SignerCheck> LamportsChecked for Mut<Signer<'_, UncheckedAccount<'_>, C>> {}
+
+/// Moves lamports out of accounts owned by the program, which are debited directly
+/// instead of through the System program.
+pub trait ProgramLamports: WritableAccount {
+    /// Sends `amount` lamports to `to`, failing with `InsufficientFunds` if the account
+    /// would no longer be rent exempt for its current data length.
+    #[inline(always)]
+    fn send(&self, to: &impl WritableAccount, amount: u64, rent: &Rent) -> Result<(), Error> {
+        let remaining = self
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        let data_len = self.as_ref().data_len();
+        if remaining < rent.try_minimum_balance(data_len)? {
+            return Err(ProgramError::InsufficientFunds.into());
+        }
+
+        let recipient_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.set_lamports(remaining);
+        to.set_lamports(recipient_lamports);
+
+        Ok(())
+    }
+
+    /// Sends every lamport of the account to `to`, leaving it to be garbage collected
+    /// at the end of the transaction.
+    ///
+    /// Prefer [`CloseAccount::close`](crate::CloseAccount::close) to also release the data
+    /// of the account.
+    #[inline(always)]
+    fn drain(&self, to: &impl WritableAccount) -> Result<(), Error> {
+        let recipient_lamports = to
+            .lamports()
+            .checked_add(self.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.set_lamports(0);
+        to.set_lamports(recipient_lamports);
+
+        Ok(())
+    }
+}
+
+impl<T: Discriminator> ProgramLamports for Mut<Account<'_, T>> {}
+impl<T: Discriminator, C: SignerCheck> ProgramLamports for Mut<Signer<'_, Account<'_, T>, C>> {}
@@ -3,6 +3,18 @@
 mod close;
 mod create;
 mod lamport;
+mod lookup_table;
+mod memo;
+mod migrate;
+mod nonce;
+#[cfg(feature = "bytemuck")]
+mod stake;
 mod system;
+#[cfg(feature = "bytemuck")]
+mod trailing;
 
-pub use {close::*, create::*, lamport::*, system::*};
+pub use {
+    close::*, create::*, lamport::*, lookup_table::*, memo::*, migrate::*, nonce::*, system::*,
+};
+#[cfg(feature = "bytemuck")]
+pub use {stake::*, trailing::*};
@@ -0,0 +1,196 @@
+use {
+    core::{mem::MaybeUninit, ptr, slice},
+    pinocchio::{
+        cpi::{self, invoke_signed},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView, Address,
+    },
+    typhoon_accounts::{
+        AddressLookupTable, Program, ReadableAccount, System, WritableAccount,
+        ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    },
+    typhoon_errors::Error,
+};
+
+/// The maximum number of addresses added by a single [`LookupTableCpi::extend_lookup_table`].
+pub const MAX_EXTEND_LOOKUP_TABLE_ADDRESSES: usize = 32;
+
+/// The length of the instruction tag and of the address count of `ExtendLookupTable`.
+const EXTEND_HEADER_LEN: usize = 12;
+
+pub trait LookupTableCpi: AsRef<AccountView> {
+    /// Creates `lookup_table`, the address derived from `authority` and `recent_slot`
+    /// with `bump`, funded by `payer`.
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn create_lookup_table(
+        &self,
+        lookup_table: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        payer: &impl WritableAccount,
+        system_program: &Program<'_, System>,
+        recent_slot: u64,
+        bump: u8,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        let mut data = [0; 13];
+        data[4..12].copy_from_slice(&recent_slot.to_le_bytes());
+        data[12] = bump;
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(lookup_table.address()),
+                    InstructionAccount::readonly(authority.address()),
+                    InstructionAccount::writable_signer(payer.address()),
+                    InstructionAccount::readonly(system_program.address()),
+                ],
+                data: &data,
+            },
+            &[
+                lookup_table.as_ref(),
+                authority.as_ref(),
+                payer.as_ref(),
+                system_program.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Appends `addresses` to `lookup_table`, `payer` funding the rent of the new size.
+    #[inline(always)]
+    fn extend_lookup_table(
+        &self,
+        lookup_table: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        payer: &impl WritableAccount,
+        system_program: &Program<'_, System>,
+        addresses: &[Address],
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        if addresses.len() > MAX_EXTEND_LOOKUP_TABLE_ADDRESSES {
+            return Err(ProgramError::InvalidArgument.into());
+        }
+
+        const UNINIT: MaybeUninit<u8> = MaybeUninit::uninit();
+        let mut data = [UNINIT; EXTEND_HEADER_LEN + MAX_EXTEND_LOOKUP_TABLE_ADDRESSES * 32];
+        let len = EXTEND_HEADER_LEN + addresses.len() * 32;
+        let mut header = [0; EXTEND_HEADER_LEN];
+        header[0] = 2;
+        header[4..].copy_from_slice(&(addresses.len() as u64).to_le_bytes());
+
+        // SAFETY: `data` holds the header and up to `MAX_EXTEND_LOOKUP_TABLE_ADDRESSES`
+        // addresses, and its first `len` bytes are initialized here.
+        let data = unsafe {
+            let ptr = data.as_mut_ptr() as *mut u8;
+            ptr::copy_nonoverlapping(header.as_ptr(), ptr, EXTEND_HEADER_LEN);
+            ptr::copy_nonoverlapping(
+                addresses.as_ptr() as *const u8,
+                ptr.add(EXTEND_HEADER_LEN),
+                addresses.len() * 32,
+            );
+            slice::from_raw_parts(ptr, len)
+        };
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(lookup_table.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                    InstructionAccount::writable_signer(payer.address()),
+                    InstructionAccount::readonly(system_program.address()),
+                ],
+                data,
+            },
+            &[
+                lookup_table.as_ref(),
+                authority.as_ref(),
+                payer.as_ref(),
+                system_program.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Freezes `lookup_table`, removing its authority.
+    #[inline(always)]
+    fn freeze_lookup_table(
+        &self,
+        lookup_table: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_authority_instruction(1, lookup_table, authority, seeds)
+    }
+
+    /// Deactivates `lookup_table`, which can be closed once the deactivation slot is no
+    /// longer in the slot hashes.
+    #[inline(always)]
+    fn deactivate_lookup_table(
+        &self,
+        lookup_table: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_authority_instruction(3, lookup_table, authority, seeds)
+    }
+
+    /// Closes the deactivated `lookup_table`, sending its lamports to `recipient`.
+    #[inline(always)]
+    fn close_lookup_table(
+        &self,
+        lookup_table: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        recipient: &impl WritableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_signed(
+            &InstructionView {
+                program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(lookup_table.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                    InstructionAccount::writable(recipient.address()),
+                ],
+                data: &[4, 0, 0, 0],
+            },
+            &[
+                lookup_table.as_ref(),
+                authority.as_ref(),
+                recipient.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+}
+
+impl LookupTableCpi for Program<'_, AddressLookupTable> {}
+
+/// Invokes the instruction `tag`, which only takes the lookup table and its authority.
+#[inline(always)]
+fn invoke_authority_instruction(
+    tag: u8,
+    lookup_table: &impl WritableAccount,
+    authority: &impl ReadableAccount,
+    seeds: Option<&[cpi::Signer]>,
+) -> Result<(), Error> {
+    invoke_signed(
+        &InstructionView {
+            program_id: &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable(lookup_table.address()),
+                InstructionAccount::readonly_signer(authority.address()),
+            ],
+            data: &[tag, 0, 0, 0],
+        },
+        &[lookup_table.as_ref(), authority.as_ref()],
+        seeds.unwrap_or_default(),
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,54 @@
+use {
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        cpi::{self, invoke_signed_with_bounds},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        AccountView,
+    },
+    typhoon_accounts::{Memo, Program, MEMO_PROGRAM_ID},
+    typhoon_errors::Error,
+};
+
+/// The maximum number of signers of a memo emitted with [`MemoCpi`].
+pub const MAX_MEMO_SIGNERS: usize = 16;
+
+pub trait MemoCpi: AsRef<AccountView> {
+    /// Emits `memo` through the Memo program, which requires every account of `signers`
+    /// to sign it.
+    ///
+    /// PDA signers must provide their signer seeds via `seeds`.
+    #[inline(always)]
+    fn memo(
+        &self,
+        memo: &str,
+        signers: &[&AccountView],
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        if signers.len() > MAX_MEMO_SIGNERS {
+            return Err(ProgramError::InvalidArgument.into());
+        }
+
+        const UNINIT: MaybeUninit<InstructionAccount> = MaybeUninit::uninit();
+        let mut accounts = [UNINIT; MAX_MEMO_SIGNERS];
+        for (account, signer) in accounts.iter_mut().zip(signers) {
+            account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        let instruction = InstructionView {
+            program_id: &MEMO_PROGRAM_ID,
+            // SAFETY: The first `signers.len()` accounts were initialized above.
+            accounts: unsafe { slice::from_raw_parts(accounts.as_ptr().cast(), signers.len()) },
+            data: memo.as_bytes(),
+        };
+
+        invoke_signed_with_bounds::<MAX_MEMO_SIGNERS>(
+            &instruction,
+            signers,
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+}
+
+impl MemoCpi for Program<'_, Memo> {}
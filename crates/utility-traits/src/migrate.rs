@@ -0,0 +1,50 @@
+use {
+    pinocchio::{sysvars::rent::Rent, AccountView},
+    typhoon_accounts::{Account, Mut, VersionedAccount, WritableAccount},
+    typhoon_errors::Error,
+    typhoon_traits::{DataStrategy, Migratable, Write},
+    typhoon_utility::resize_with_minimum_balance,
+};
+
+pub trait MigrateAccount<'a, T>
+where
+    Self: Sized + WritableAccount + Into<&'a AccountView>,
+    T: Migratable + DataStrategy,
+    T::Strategy: Write<T>,
+{
+    /// Rewrites the account with the latest layout of `T`.
+    ///
+    /// The account is resized to the new layout and the `payer` tops up the
+    /// lamports if it is no longer rent exempt. Accounts already using the
+    /// latest layout are left untouched.
+    #[inline(always)]
+    fn migrate(
+        self,
+        payer: &impl WritableAccount,
+        rent: &Rent,
+    ) -> Result<Mut<Account<'a, T>>, Error> {
+        let Some(latest) = T::migrate(&self.raw_data()?)? else {
+            return Ok(Mut::from_raw_info(self.into()));
+        };
+
+        let info = self.into();
+        let space = T::DISCRIMINATOR.len() + <T::Strategy as Write<T>>::size(&latest)?;
+        resize_with_minimum_balance(info, space, payer.as_ref(), rent)?;
+
+        {
+            let mut data = info.try_borrow_mut()?;
+            let (discriminator, data) = data.split_at_mut(T::DISCRIMINATOR.len());
+            discriminator.copy_from_slice(T::DISCRIMINATOR);
+            <T::Strategy as Write<T>>::write_into(data, &latest)?;
+        }
+
+        Ok(Mut::from_raw_info(info))
+    }
+}
+
+impl<'a, T> MigrateAccount<'a, T> for Mut<VersionedAccount<'a, T>>
+where
+    T: Migratable + DataStrategy,
+    T::Strategy: Write<T>,
+{
+}
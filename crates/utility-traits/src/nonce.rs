@@ -0,0 +1,102 @@
+use {
+    pinocchio::{cpi, sysvars::rent::Rent, AccountView, Address},
+    pinocchio_system::instructions::{
+        AdvanceNonceAccount, AuthorizeNonceAccount, InitializeNonceAccount, WithdrawNonceAccount,
+    },
+    typhoon_accounts::{
+        Mut, ReadableAccount, RecentBlockhashes, SystemAccount, Sysvar, UncheckedAccount,
+        WritableAccount,
+    },
+    typhoon_errors::Error,
+};
+
+/// The size of a durable nonce account.
+pub const NONCE_ACCOUNT_LEN: usize = 80;
+
+/// Durable nonce operations on a nonce account, an account of [`NONCE_ACCOUNT_LEN`]
+/// bytes owned by the System program.
+///
+/// PDA nonce authorities must provide their signer seeds via `seeds`.
+pub trait NonceCpi<'a>: WritableAccount + Into<&'a AccountView>
+where
+    Self: Sized,
+{
+    /// Initializes the nonce account with `authority`, once it holds enough lamports to be
+    /// rent exempt.
+    #[inline(always)]
+    fn initialize_nonce(
+        &self,
+        recent_blockhashes: &Sysvar<'_, RecentBlockhashes>,
+        rent: &Sysvar<'_, Rent>,
+        authority: &Address,
+    ) -> Result<(), Error> {
+        InitializeNonceAccount {
+            account: self.as_ref(),
+            recent_blockhashes_sysvar: recent_blockhashes.as_ref(),
+            rent_sysvar: rent.as_ref(),
+            authority,
+        }
+        .invoke()
+        .map_err(Into::into)
+    }
+
+    /// Replaces the stored nonce with a new one.
+    #[inline(always)]
+    fn advance_nonce(
+        &self,
+        recent_blockhashes: &Sysvar<'_, RecentBlockhashes>,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        AdvanceNonceAccount {
+            account: self.as_ref(),
+            recent_blockhashes_sysvar: recent_blockhashes.as_ref(),
+            authority: authority.as_ref(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+        .map_err(Into::into)
+    }
+
+    /// Withdraws `lamports` to `to`, leaving the account rent exempt or empty.
+    #[inline(always)]
+    fn withdraw_nonce(
+        &self,
+        to: &impl WritableAccount,
+        recent_blockhashes: &Sysvar<'_, RecentBlockhashes>,
+        rent: &Sysvar<'_, Rent>,
+        authority: &impl ReadableAccount,
+        lamports: u64,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        WithdrawNonceAccount {
+            account: self.as_ref(),
+            recipient: to.as_ref(),
+            recent_blockhashes_sysvar: recent_blockhashes.as_ref(),
+            rent_sysvar: rent.as_ref(),
+            authority: authority.as_ref(),
+            lamports,
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+        .map_err(Into::into)
+    }
+
+    /// Hands the nonce authority over to `new_authority`.
+    #[inline(always)]
+    fn authorize_nonce(
+        &self,
+        authority: &impl ReadableAccount,
+        new_authority: &Address,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        AuthorizeNonceAccount {
+            account: self.as_ref(),
+            authority: authority.as_ref(),
+            new_authority,
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+        .map_err(Into::into)
+    }
+}
+
+impl<'a> NonceCpi<'a> for Mut<SystemAccount<'a>> {}
+impl<'a> NonceCpi<'a> for Mut<UncheckedAccount<'a>> {}
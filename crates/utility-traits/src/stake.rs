@@ -0,0 +1,251 @@
+use {
+    pinocchio::{
+        cpi::{self, invoke_signed},
+        instruction::{InstructionAccount, InstructionView},
+        sysvars::{clock::Clock, rent::Rent},
+        AccountView, Address,
+    },
+    typhoon_accounts::{
+        Account, FromAccountInfo, FromRaw, Mut, ReadableAccount, Signer, SignerCheck, StakeAccount,
+        StakeHistory, SystemAccount, Sysvar, UncheckedAccount, WritableAccount, STAKE_PROGRAM_ID,
+    },
+    typhoon_errors::Error,
+    typhoon_utility::create_account_with_minimum_balance_signed,
+};
+
+pub trait StakeCpi: WritableAccount {
+    /// Delegates the stake to `vote`, `authority` being the staker of the account.
+    ///
+    /// `stake_config` is the legacy stake config account, no longer read by the program.
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn delegate(
+        &self,
+        vote: &impl ReadableAccount,
+        clock: &Sysvar<'_, Clock>,
+        stake_history: &Sysvar<'_, StakeHistory>,
+        stake_config: &impl ReadableAccount,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(self.address()),
+                    InstructionAccount::readonly(vote.address()),
+                    InstructionAccount::readonly(clock.address()),
+                    InstructionAccount::readonly(stake_history.address()),
+                    InstructionAccount::readonly(stake_config.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                ],
+                data: &[2, 0, 0, 0],
+            },
+            &[
+                self.as_ref(),
+                vote.as_ref(),
+                clock.as_ref(),
+                stake_history.as_ref(),
+                stake_config.as_ref(),
+                authority.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Deactivates the delegated stake, `authority` being the staker of the account.
+    #[inline(always)]
+    fn deactivate(
+        &self,
+        clock: &Sysvar<'_, Clock>,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(self.address()),
+                    InstructionAccount::readonly(clock.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                ],
+                data: &[5, 0, 0, 0],
+            },
+            &[self.as_ref(), clock.as_ref(), authority.as_ref()],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Withdraws `lamports` of the inactive stake to `to`, `authority` being the withdrawer
+    /// of the account.
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw(
+        &self,
+        to: &impl WritableAccount,
+        clock: &Sysvar<'_, Clock>,
+        stake_history: &Sysvar<'_, StakeHistory>,
+        authority: &impl ReadableAccount,
+        lamports: u64,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        let mut data = [0; 12];
+        data[0] = 4;
+        data[4..].copy_from_slice(&lamports.to_le_bytes());
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(self.address()),
+                    InstructionAccount::writable(to.address()),
+                    InstructionAccount::readonly(clock.address()),
+                    InstructionAccount::readonly(stake_history.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                ],
+                data: &data,
+            },
+            &[
+                self.as_ref(),
+                to.as_ref(),
+                clock.as_ref(),
+                stake_history.as_ref(),
+                authority.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Moves `lamports` of the stake to `split`, an uninitialized account of
+    /// [`StakeAccount::LEN`] bytes owned by the Stake program.
+    #[inline(always)]
+    fn split(
+        &self,
+        split: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        lamports: u64,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        let mut data = [0; 12];
+        data[0] = 3;
+        data[4..].copy_from_slice(&lamports.to_le_bytes());
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(self.address()),
+                    InstructionAccount::writable(split.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                ],
+                data: &data,
+            },
+            &[self.as_ref(), split.as_ref(), authority.as_ref()],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Merges `source` into the account, closing `source`.
+    #[inline(always)]
+    fn merge(
+        &self,
+        source: &impl WritableAccount,
+        clock: &Sysvar<'_, Clock>,
+        stake_history: &Sysvar<'_, StakeHistory>,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(self.address()),
+                    InstructionAccount::writable(source.address()),
+                    InstructionAccount::readonly(clock.address()),
+                    InstructionAccount::readonly(stake_history.address()),
+                    InstructionAccount::readonly_signer(authority.address()),
+                ],
+                data: &[7, 0, 0, 0],
+            },
+            &[
+                self.as_ref(),
+                source.as_ref(),
+                clock.as_ref(),
+                stake_history.as_ref(),
+                authority.as_ref(),
+            ],
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+}
+
+impl StakeCpi for Mut<Account<'_, StakeAccount>> {}
+impl StakeCpi for Mut<UncheckedAccount<'_>> {}
+
+pub trait CreateStakeAccount<'a, T>
+where
+    Self: Sized + Into<&'a AccountView>,
+    T: ReadableAccount + FromAccountInfo<'a> + FromRaw<'a>,
+{
+    /// Creates a stake account funded by `payer` and initializes it with `staker` and
+    /// `withdrawer` as authorities, without lockup.
+    #[inline]
+    fn create_stake_account(
+        self,
+        rent: &Rent,
+        payer: &impl WritableAccount,
+        rent_sysvar: &Sysvar<'_, Rent>,
+        staker: &Address,
+        withdrawer: &Address,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<Mut<T>, Error> {
+        let info = self.into();
+        create_account_with_minimum_balance_signed(
+            info,
+            StakeAccount::LEN,
+            &STAKE_PROGRAM_ID,
+            payer.as_ref(),
+            rent,
+            seeds.unwrap_or_default(),
+        )?;
+
+        // `Initialize` with `Authorized` and a zeroed `Lockup`.
+        let mut data = [0; 116];
+        data[4..36].copy_from_slice(staker.as_ref());
+        data[36..68].copy_from_slice(withdrawer.as_ref());
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable(info.address()),
+                    InstructionAccount::readonly(rent_sysvar.address()),
+                ],
+                data: &data,
+            },
+            &[info, rent_sysvar.as_ref()],
+            &[],
+        )?;
+
+        Ok(Mut::from_raw_info(info))
+    }
+}
+
+macro_rules! impl_trait {
+    ($origin: ty) => {
+        impl<'a> CreateStakeAccount<'a, Account<'a, StakeAccount>> for $origin {}
+        impl<'a, C> CreateStakeAccount<'a, Signer<'a, Account<'a, StakeAccount>, C>> for $origin where
+            C: SignerCheck
+        {
+        }
+    };
+}
+
+impl_trait!(&'a AccountView);
+impl_trait!(SystemAccount<'a>);
+impl_trait!(UncheckedAccount<'a>);
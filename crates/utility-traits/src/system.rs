@@ -1,10 +1,14 @@
 use {
-    pinocchio::{AccountView, Address},
+    pinocchio::{cpi, AccountView, Address},
     pinocchio_system::instructions::{Allocate, Assign, Transfer},
     typhoon_accounts::{
-        Mut, Signer as SignerAccount, SignerCheck, SystemAccount, UncheckedAccount, WritableAccount,
+        Mut, ReadableAccount, Signer as SignerAccount, SignerCheck, SystemAccount,
+        UncheckedAccount, WritableAccount,
     },
     typhoon_errors::Error,
+    typhoon_utility::{
+        allocate_with_seed_signed, assign_with_seed_signed, transfer_with_seed_signed,
+    },
 };
 
 pub trait SystemCpi<'a>: WritableAccount + Into<&'a AccountView>
@@ -45,3 +49,77 @@ where
 
 impl<'a, C: SignerCheck> SystemCpi<'a> for Mut<SignerAccount<'a, SystemAccount<'a>, C>> {}
 impl<'a, C: SignerCheck> SystemCpi<'a> for Mut<SignerAccount<'a, UncheckedAccount<'a>, C>> {}
+
+/// System operations on an account derived from a `base` account and a `seed`, which
+/// are authorized by the signature of `base` instead of the account itself.
+///
+/// PDA `base` accounts must provide their signer seeds via `seeds`.
+pub trait SystemWithSeedCpi<'a>: WritableAccount + Into<&'a AccountView>
+where
+    Self: Sized,
+{
+    #[inline(always)]
+    fn allocate_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        seed: &str,
+        new_space: u64,
+        owner: &Address,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        allocate_with_seed_signed(
+            self.as_ref(),
+            base.as_ref(),
+            seed,
+            new_space,
+            owner,
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    #[inline(always)]
+    fn assign_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        seed: &str,
+        owner: &Address,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        assign_with_seed_signed(
+            self.as_ref(),
+            base.as_ref(),
+            seed,
+            owner,
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+
+    /// Transfers `amount` lamports to `to`, `owner` being the program used to derive the
+    /// address of the account.
+    #[inline(always)]
+    fn transfer_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        seed: &str,
+        owner: &Address,
+        to: &impl WritableAccount,
+        amount: u64,
+        seeds: Option<&[cpi::Signer]>,
+    ) -> Result<(), Error> {
+        transfer_with_seed_signed(
+            self.as_ref(),
+            base.as_ref(),
+            seed,
+            owner,
+            to.as_ref(),
+            amount,
+            seeds.unwrap_or_default(),
+        )
+        .map_err(Into::into)
+    }
+}
+
+impl<'a> SystemWithSeedCpi<'a> for Mut<SystemAccount<'a>> {}
+impl<'a> SystemWithSeedCpi<'a> for Mut<UncheckedAccount<'a>> {}
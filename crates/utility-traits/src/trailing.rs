@@ -0,0 +1,59 @@
+use {
+    pinocchio::{error::ProgramError, sysvars::rent::Rent},
+    typhoon_accounts::{WritableAccount, WritableAccountData},
+    typhoon_errors::Error,
+    typhoon_traits::{DataStrategy, MutAccessor, Trailing, TrailingStrategy},
+    typhoon_utility::resize_with_minimum_balance,
+};
+
+pub trait TrailingEntries<E>: WritableAccountData
+where
+    Self::Data: DataStrategy<Strategy = TrailingStrategy<E>>,
+    TrailingStrategy<E>:
+        for<'a> MutAccessor<'a, Self::Data, Data = &'a mut Trailing<Self::Data, E>>,
+    E: Copy,
+{
+    /// Appends `entry`, growing the account and topping up its rent from the `payer`.
+    #[inline(always)]
+    fn push(&self, entry: E, payer: &impl WritableAccount, rent: &Rent) -> Result<(), Error> {
+        let new_len = self.as_ref().data_len() + core::mem::size_of::<E>();
+        resize_with_minimum_balance(self.as_ref(), new_len, payer.as_ref(), rent)?;
+
+        let mut data = self.mut_data()?;
+        let last = data
+            .entries
+            .last_mut()
+            .ok_or(ProgramError::InvalidAccountData)?;
+        *last = entry;
+
+        Ok(())
+    }
+
+    /// Removes and returns the entry at `index`, shifting the following entries
+    /// and shrinking the account.
+    #[inline(always)]
+    fn remove(&self, index: usize) -> Result<E, Error> {
+        let entry = {
+            let mut data = self.mut_data()?;
+            let entry = *data
+                .entries
+                .get(index)
+                .ok_or(ProgramError::InvalidArgument)?;
+            data.entries.copy_within(index + 1.., index);
+            entry
+        };
+
+        self.resize(self.as_ref().data_len() - core::mem::size_of::<E>())?;
+
+        Ok(entry)
+    }
+}
+
+impl<T, E> TrailingEntries<E> for T
+where
+    T: WritableAccountData,
+    T::Data: DataStrategy<Strategy = TrailingStrategy<E>>,
+    TrailingStrategy<E>: for<'a> MutAccessor<'a, T::Data, Data = &'a mut Trailing<T::Data, E>>,
+    E: Copy,
+{
+}
@@ -0,0 +1,192 @@
+use {
+    super::{write_bytes, MaybeUninitWriter},
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        address::Address,
+        cpi::{invoke_signed_with_bounds, Signer},
+        instruction::{InstructionAccount, InstructionView},
+        AccountView,
+    },
+    typhoon_errors::{Error, ErrorCode},
+};
+
+/// Builds a cross-program instruction into caller-provided buffers without allocating.
+///
+/// Accounts and data that do not fit in the buffers are rejected with
+/// [`ErrorCode::BufferFull`] instead of being truncated.
+pub struct CpiBuilder<'a, 'b> {
+    data: MaybeUninitWriter<'b>,
+    metas: &'b mut [MaybeUninit<InstructionAccount<'a>>],
+    infos: &'b mut [MaybeUninit<&'a AccountView>],
+    len: usize,
+}
+
+impl<'a, 'b> CpiBuilder<'a, 'b> {
+    #[inline(always)]
+    pub fn new(
+        data: &'b mut [MaybeUninit<u8>],
+        metas: &'b mut [MaybeUninit<InstructionAccount<'a>>],
+        infos: &'b mut [MaybeUninit<&'a AccountView>],
+    ) -> Self {
+        Self {
+            data: MaybeUninitWriter::new(data, 0),
+            metas,
+            infos,
+            len: 0,
+        }
+    }
+
+    /// Appends `bytes` to the instruction data.
+    #[inline(always)]
+    pub fn data(&mut self, bytes: &[u8]) -> Result<(), ErrorCode> {
+        // SAFETY: The reserved bytes are fully written from `bytes`.
+        write_bytes(unsafe { self.data.reserve(bytes.len())? }, bytes);
+
+        Ok(())
+    }
+
+    /// Reserves `len` bytes of instruction data for the caller to serialize into.
+    ///
+    /// # Safety
+    ///
+    /// Every returned byte must be written before the instruction is invoked.
+    #[inline(always)]
+    pub unsafe fn reserve_data(&mut self, len: usize) -> Result<&mut [MaybeUninit<u8>], ErrorCode> {
+        self.data.reserve(len)
+    }
+
+    /// Appends an account with the given privileges to the instruction.
+    #[inline(always)]
+    pub fn account(
+        &mut self,
+        account: &'a AccountView,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> Result<(), ErrorCode> {
+        let (Some(meta), Some(info)) = (self.metas.get_mut(self.len), self.infos.get_mut(self.len))
+        else {
+            return Err(ErrorCode::BufferFull);
+        };
+
+        meta.write(InstructionAccount::new(
+            account.address(),
+            is_writable,
+            is_signer,
+        ));
+        info.write(account);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends `accounts` with the privileges they have in the current instruction.
+    #[inline(always)]
+    pub fn accounts(
+        &mut self,
+        accounts: impl IntoIterator<Item = &'a AccountView>,
+    ) -> Result<(), ErrorCode> {
+        for account in accounts {
+            self.account(account, account.is_writable(), account.is_signer())?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn invoke<const MAX_ACCOUNTS: usize>(&self, program_id: &Address) -> Result<(), Error> {
+        self.invoke_signed::<MAX_ACCOUNTS>(program_id, &[])
+    }
+
+    /// Invokes the instruction, checking at most `MAX_ACCOUNTS` accounts on the stack.
+    #[inline(always)]
+    pub fn invoke_signed<const MAX_ACCOUNTS: usize>(
+        &self,
+        program_id: &Address,
+        seeds: &[Signer],
+    ) -> Result<(), Error> {
+        if self.len > MAX_ACCOUNTS {
+            return Err(ErrorCode::BufferFull.into());
+        }
+
+        // SAFETY: The first `len` metas and infos have been written by `account`.
+        let (metas, infos) = unsafe {
+            (
+                slice::from_raw_parts(self.metas.as_ptr() as *const InstructionAccount, self.len),
+                slice::from_raw_parts(self.infos.as_ptr() as *const &AccountView, self.len),
+            )
+        };
+        let instruction = InstructionView {
+            program_id,
+            data: self.data.initialized(),
+            accounts: metas,
+        };
+
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(&instruction, infos, seeds).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::bytes::{UNINIT_ACC_VIEW, UNINIT_BYTE, UNINIT_INS_ACC},
+        pinocchio::{account::RuntimeAccount, error::ProgramError},
+    };
+
+    #[test]
+    fn data_does_not_truncate() {
+        let mut data = [UNINIT_BYTE; 4];
+        let mut builder = CpiBuilder::new(&mut data, &mut [], &mut []);
+
+        builder.data(&[1, 2, 3]).unwrap();
+        assert_eq!(builder.data(&[4, 5]), Err(ErrorCode::BufferFull));
+        builder.data(&[4]).unwrap();
+        assert_eq!(builder.data.initialized(), &[1, 2, 3, 4]);
+
+        assert_eq!(
+            unsafe { builder.reserve_data(1) }.map(|bytes| bytes.len()),
+            Err(ErrorCode::BufferFull)
+        );
+    }
+
+    #[test]
+    fn accounts_do_not_overflow() {
+        let mut raw = [RuntimeAccount::default(), RuntimeAccount::default()];
+        let [first, second] = raw
+            .each_mut()
+            .map(|raw| unsafe { AccountView::new_unchecked(raw) });
+        let mut metas = [UNINIT_INS_ACC; 1];
+        let mut infos = [UNINIT_ACC_VIEW; 1];
+        let mut builder = CpiBuilder::new(&mut [], &mut metas, &mut infos);
+
+        builder.account(&first, true, false).unwrap();
+        assert_eq!(
+            builder.account(&second, false, false),
+            Err(ErrorCode::BufferFull)
+        );
+        assert_eq!(
+            builder.accounts([&first, &second]),
+            Err(ErrorCode::BufferFull)
+        );
+        assert_eq!(builder.len, 1);
+    }
+
+    #[test]
+    fn invoke_checks_max_accounts() {
+        let mut raw = [RuntimeAccount::default(), RuntimeAccount::default()];
+        let [first, second] = raw
+            .each_mut()
+            .map(|raw| unsafe { AccountView::new_unchecked(raw) });
+        let mut metas = [UNINIT_INS_ACC; 2];
+        let mut infos = [UNINIT_ACC_VIEW; 2];
+        let mut builder = CpiBuilder::new(&mut [], &mut metas, &mut infos);
+        builder.accounts([&first, &second]).unwrap();
+
+        assert_eq!(
+            builder
+                .invoke_signed::<1>(&Address::default(), &[])
+                .map_err(ProgramError::from),
+            Err(ErrorCode::BufferFull.into())
+        );
+    }
+}
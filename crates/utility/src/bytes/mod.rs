@@ -1,10 +1,11 @@
+mod cpi_builder;
 mod writer;
 
-pub use writer::*;
 use {
     core::mem::MaybeUninit,
     pinocchio::{cpi::Seed, instruction::InstructionAccount, AccountView},
 };
+pub use {cpi_builder::*, writer::*};
 
 pub const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::<u8>::uninit();
 pub const UNINIT_SEED: MaybeUninit<Seed> = MaybeUninit::<Seed>::uninit();
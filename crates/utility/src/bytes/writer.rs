@@ -45,6 +45,25 @@ impl<'a> MaybeUninitWriter<'a> {
 
         Ok(to_write)
     }
+
+    /// Advances the writer by `len` bytes and returns them for the caller to fill.
+    ///
+    /// Fails with [`ErrorCode::BufferFull`] instead of returning fewer bytes.
+    ///
+    /// # Safety
+    ///
+    /// Every returned byte must be written before [`Self::initialized`] is read.
+    #[inline(always)]
+    pub unsafe fn reserve(&mut self, len: usize) -> Result<&mut [MaybeUninit<u8>], ErrorCode> {
+        let end = self.position.checked_add(len).ok_or_else(buffer_full)?;
+        let reserved = self
+            .buffer
+            .get_mut(self.position..end)
+            .ok_or_else(buffer_full)?;
+        self.position = end;
+
+        Ok(reserved)
+    }
 }
 
 #[cold]
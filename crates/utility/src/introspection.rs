@@ -0,0 +1,244 @@
+//! Zero-copy introspection of the current transaction through the `Instructions` sysvar.
+
+pub use pinocchio::sysvars::instructions::{
+    Instructions, IntrospectedInstruction, IntrospectedInstructionAccount, INSTRUCTIONS_ID,
+};
+use {
+    crate::precompiles::{
+        Ed25519SignatureOffsets, InstructionDataSource, Secp256k1SignatureOffsets,
+        SignatureOffsets, Signatures,
+    },
+    core::ops::Deref,
+    pinocchio::{
+        address::{address_eq, Address},
+        error::ProgramError,
+    },
+    typhoon_errors::{Error, ErrorCode},
+};
+
+/// Iterator over the instructions of the current transaction.
+pub struct InstructionsIter<'a, T: Deref<Target = [u8]>> {
+    instructions: &'a Instructions<T>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T: Deref<Target = [u8]>> Iterator for InstructionsIter<'a, T> {
+    type Item = IntrospectedInstruction<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        // SAFETY: `index` is lower than the number of instructions.
+        let instruction = unsafe {
+            self.instructions
+                .deserialize_instruction_unchecked(self.index)
+        };
+        self.index += 1;
+        Some(instruction)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Deref<Target = [u8]>> ExactSizeIterator for InstructionsIter<'_, T> {}
+
+/// Iterator over the accounts of an introspected instruction.
+pub struct InstructionAccountsIter<'a> {
+    instruction: &'a IntrospectedInstruction<'a>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a> Iterator for InstructionAccountsIter<'a> {
+    type Item = &'a IntrospectedInstructionAccount;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        // SAFETY: `index` is lower than the number of accounts.
+        let account = unsafe {
+            self.instruction
+                .get_instruction_account_at_unchecked(self.index)
+        };
+        self.index += 1;
+        Some(account)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for InstructionAccountsIter<'_> {}
+
+/// Extension methods for the `Instructions` sysvar.
+pub trait InstructionsExt<T: Deref<Target = [u8]>> {
+    /// Iterates over all the instructions of the transaction.
+    fn iter(&self) -> InstructionsIter<'_, T>;
+
+    /// Returns the instruction being executed.
+    fn current_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError>;
+
+    /// Returns the instruction following the one being executed.
+    fn next_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError>;
+
+    /// Returns the instruction preceding the one being executed.
+    fn previous_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError>;
+}
+
+impl<T: Deref<Target = [u8]>> InstructionsExt<T> for Instructions<T> {
+    #[inline(always)]
+    fn iter(&self) -> InstructionsIter<'_, T> {
+        InstructionsIter {
+            instructions: self,
+            index: 0,
+            len: self.num_instructions(),
+        }
+    }
+
+    #[inline(always)]
+    fn current_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.load_instruction_at(self.load_current_index() as usize)
+    }
+
+    #[inline(always)]
+    fn next_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.get_instruction_relative(1)
+    }
+
+    #[inline(always)]
+    fn previous_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.get_instruction_relative(-1)
+    }
+}
+
+/// Extension methods for an introspected instruction.
+pub trait IntrospectedInstructionExt {
+    /// Iterates over the accounts of the instruction.
+    fn accounts(&self) -> InstructionAccountsIter<'_>;
+
+    /// Returns `true` if the instruction calls `program_id` with data starting with `discriminator`.
+    fn is_instruction(&self, program_id: &Address, discriminator: &[u8]) -> bool;
+}
+
+impl IntrospectedInstructionExt for IntrospectedInstruction<'_> {
+    #[inline(always)]
+    fn accounts(&self) -> InstructionAccountsIter<'_> {
+        InstructionAccountsIter {
+            instruction: self,
+            index: 0,
+            len: self.num_account_metas(),
+        }
+    }
+
+    #[inline(always)]
+    fn is_instruction(&self, program_id: &Address, discriminator: &[u8]) -> bool {
+        address_eq(self.get_program_id(), program_id)
+            && self.get_instruction_data().starts_with(discriminator)
+    }
+}
+
+/// Returns the data of `ix`, borrowed for as long as the sysvar data.
+#[inline(always)]
+fn instruction_data<'a>(ix: &IntrospectedInstruction<'a>) -> &'a [u8] {
+    let data = ix.get_instruction_data();
+    // SAFETY: The instruction data lives in the sysvar data, which is borrowed for `'a`.
+    unsafe { core::slice::from_raw_parts(data.as_ptr(), data.len()) }
+}
+
+/// The instructions of the transaction, as referenced by signature offsets.
+pub struct SysvarInstructionData<'a, T: Deref<Target = [u8]>>(pub &'a Instructions<T>);
+
+impl<'a, T: Deref<Target = [u8]>> InstructionDataSource<'a> for SysvarInstructionData<'a, T> {
+    #[inline(always)]
+    fn instruction_data(&self, index: u16) -> Option<&'a [u8]> {
+        self.0
+            .load_instruction_at(index as usize)
+            .ok()
+            .map(|ix| instruction_data(&ix))
+    }
+}
+
+/// Parses the signatures verified by `ix`, an instruction of the `O` precompile, resolving
+/// the data referenced in other instructions of the transaction.
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if `ix` does not call the `O` precompile.
+#[inline]
+pub fn verified_signatures<'a, O, T>(
+    instructions: &'a Instructions<T>,
+    ix: &IntrospectedInstruction<'a>,
+) -> Result<Signatures<'a, O, SysvarInstructionData<'a, T>>, ProgramError>
+where
+    O: SignatureOffsets,
+    T: Deref<Target = [u8]>,
+{
+    if !address_eq(ix.get_program_id(), &O::PROGRAM_ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Signatures::new(instruction_data(ix), SysvarInstructionData(instructions))
+}
+
+/// Checks that an instruction preceding the current one verifies the Ed25519 signature of
+/// `message` by `pubkey`.
+///
+/// Returns [`ErrorCode::SignatureVerificationMissing`] otherwise.
+#[inline]
+pub fn require_ed25519_verify<T: Deref<Target = [u8]>>(
+    instructions: &Instructions<T>,
+    pubkey: &Address,
+    message: &[u8],
+) -> Result<(), Error> {
+    require_verified::<Ed25519SignatureOffsets, T>(instructions, pubkey.as_ref(), message)
+}
+
+/// Checks that an instruction preceding the current one verifies the Secp256k1 signature of
+/// `message` by the key of `eth_address`.
+///
+/// Returns [`ErrorCode::SignatureVerificationMissing`] otherwise.
+#[inline]
+pub fn require_secp256k1_verify<T: Deref<Target = [u8]>>(
+    instructions: &Instructions<T>,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<(), Error> {
+    require_verified::<Secp256k1SignatureOffsets, T>(instructions, eth_address, message)
+}
+
+fn require_verified<O, T>(
+    instructions: &Instructions<T>,
+    pubkey: &[u8],
+    message: &[u8],
+) -> Result<(), Error>
+where
+    O: SignatureOffsets,
+    T: Deref<Target = [u8]>,
+{
+    let current = instructions.load_current_index() as usize;
+    let verified = instructions.iter().take(current).any(|ix| {
+        verified_signatures::<O, T>(instructions, &ix).is_ok_and(|mut signatures| {
+            signatures.any(|signature| {
+                signature.is_ok_and(|signature| {
+                    signature.pubkey.as_ref() == pubkey && signature.message == message
+                })
+            })
+        })
+    });
+
+    if verified {
+        Ok(())
+    } else {
+        Err(ErrorCode::SignatureVerificationMissing.into())
+    }
+}
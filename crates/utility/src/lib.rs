@@ -1,7 +1,12 @@
 #![no_std]
 
 mod create_account;
+mod resize;
+mod with_seed;
 
 pub mod bytes;
+pub mod introspection;
+pub mod precompiles;
+pub mod return_data;
 
-pub use create_account::*;
+pub use {create_account::*, resize::*, with_seed::*};
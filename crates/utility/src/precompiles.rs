@@ -0,0 +1,502 @@
+//! Parsers for the instruction data of the Ed25519 and Secp256k1 signature verification
+//! precompiles.
+//!
+//! A precompile instruction only succeeds if all the signatures it lists are valid, so the
+//! `(pubkey, message, signature)` triples parsed from an instruction of the transaction can
+//! be trusted by the handlers introspecting it.
+
+use {
+    core::{fmt::Debug, marker::PhantomData, slice::ChunksExact},
+    pinocchio::{address::Address, error::ProgramError},
+};
+
+/// The address of the Ed25519 signature verification precompile.
+pub const ED25519_PROGRAM_ID: Address =
+    Address::from_str_const("Ed25519SigVerify111111111111111111111111111");
+
+/// The address of the Secp256k1 signature verification precompile.
+pub const SECP256K1_PROGRAM_ID: Address =
+    Address::from_str_const("KeccakSecp256k11111111111111111111111111111");
+
+/// Fixed-size byte array read in place from instruction data.
+pub trait ByteArray: AsRef<[u8]> + Debug + Eq {
+    /// Reads the array at `offset` in `data`.
+    fn read(data: &[u8], offset: usize) -> Option<&Self>;
+}
+
+impl<const N: usize> ByteArray for [u8; N] {
+    #[inline(always)]
+    fn read(data: &[u8], offset: usize) -> Option<&Self> {
+        data.get(offset..)?.first_chunk()
+    }
+}
+
+/// Source of the data of the transaction instructions referenced by signature offsets.
+pub trait InstructionDataSource<'a> {
+    /// Returns the data of the instruction at `index` of the transaction.
+    fn instruction_data(&self, index: u16) -> Option<&'a [u8]>;
+}
+
+impl<'a, F: Fn(u16) -> Option<&'a [u8]>> InstructionDataSource<'a> for F {
+    #[inline(always)]
+    fn instruction_data(&self, index: u16) -> Option<&'a [u8]> {
+        self(index)
+    }
+}
+
+/// Layout of the offsets describing each signature verified by a precompile.
+pub trait SignatureOffsets: Copy + Debug + Eq {
+    /// The address of the precompile.
+    const PROGRAM_ID: Address;
+
+    /// The position of the first offsets in the instruction data.
+    const START: usize;
+
+    /// The serialized size of the offsets.
+    const LEN: usize;
+
+    /// The public key, or the address derived from it, verifying the signature.
+    type Pubkey: ByteArray + 'static;
+
+    /// The serialized signature.
+    type Signature: ByteArray + 'static;
+
+    /// Parses the offsets from `bytes`, which holds exactly [`Self::LEN`] bytes.
+    fn parse(bytes: &[u8]) -> Self;
+
+    /// Reads the signature described by the offsets.
+    ///
+    /// `current` is the data of the precompile instruction and `source` provides the data of
+    /// the other instructions of the transaction.
+    fn resolve<'a>(
+        &self,
+        current: &'a [u8],
+        source: &impl InstructionDataSource<'a>,
+    ) -> Result<VerifiedSignature<'a, Self>, ProgramError>;
+}
+
+/// A signature verified by a precompile instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifiedSignature<'a, O: SignatureOffsets> {
+    /// The public key, or the address derived from it, verifying the signature.
+    pub pubkey: &'a O::Pubkey,
+    /// The signed message.
+    pub message: &'a [u8],
+    /// The signature.
+    pub signature: &'a O::Signature,
+}
+
+impl<'a, O: SignatureOffsets> VerifiedSignature<'a, O> {
+    /// Returns the `(pubkey, message, signature)` triple.
+    #[inline(always)]
+    pub fn into_parts(self) -> (&'a O::Pubkey, &'a [u8], &'a O::Signature) {
+        (self.pubkey, self.message, self.signature)
+    }
+}
+
+/// A signature verified by the Ed25519 precompile.
+pub type Ed25519Signature<'a> = VerifiedSignature<'a, Ed25519SignatureOffsets>;
+
+/// A signature verified by the Secp256k1 precompile.
+pub type Secp256k1Signature<'a> = VerifiedSignature<'a, Secp256k1SignatureOffsets>;
+
+/// Offsets of a signature verified by the Ed25519 precompile.
+///
+/// An instruction index of `u16::MAX` refers to the precompile instruction itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ed25519SignatureOffsets {
+    pub signature_offset: u16,
+    pub signature_instruction_index: u16,
+    pub public_key_offset: u16,
+    pub public_key_instruction_index: u16,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+    pub message_instruction_index: u16,
+}
+
+impl SignatureOffsets for Ed25519SignatureOffsets {
+    const PROGRAM_ID: Address = ED25519_PROGRAM_ID;
+
+    // The signature count is followed by a padding byte.
+    const START: usize = 2;
+
+    const LEN: usize = 14;
+
+    type Pubkey = [u8; 32];
+
+    type Signature = [u8; 64];
+
+    #[inline]
+    fn parse(bytes: &[u8]) -> Self {
+        let field = |i: usize| u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+
+        Ed25519SignatureOffsets {
+            signature_offset: field(0),
+            signature_instruction_index: field(1),
+            public_key_offset: field(2),
+            public_key_instruction_index: field(3),
+            message_data_offset: field(4),
+            message_data_size: field(5),
+            message_instruction_index: field(6),
+        }
+    }
+
+    #[inline]
+    fn resolve<'a>(
+        &self,
+        current: &'a [u8],
+        source: &impl InstructionDataSource<'a>,
+    ) -> Result<VerifiedSignature<'a, Self>, ProgramError> {
+        let data = |index: u16| {
+            if index == u16::MAX {
+                Some(current)
+            } else {
+                source.instruction_data(index)
+            }
+        };
+        let signature = data(self.signature_instruction_index)
+            .and_then(|data| ByteArray::read(data, self.signature_offset as usize));
+        let pubkey = data(self.public_key_instruction_index)
+            .and_then(|data| ByteArray::read(data, self.public_key_offset as usize));
+        let message = data(self.message_instruction_index).and_then(|data| {
+            read_slice(
+                data,
+                self.message_data_offset,
+                self.message_data_size as usize,
+            )
+        });
+
+        match (pubkey, message, signature) {
+            (Some(pubkey), Some(message), Some(signature)) => Ok(VerifiedSignature {
+                pubkey,
+                message,
+                signature,
+            }),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Offsets of a signature verified by the Secp256k1 precompile.
+///
+/// The signature is followed by its recovery id and verified against the Ethereum address
+/// of the public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1SignatureOffsets {
+    pub signature_offset: u16,
+    pub signature_instruction_index: u8,
+    pub eth_address_offset: u16,
+    pub eth_address_instruction_index: u8,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+    pub message_instruction_index: u8,
+}
+
+impl SignatureOffsets for Secp256k1SignatureOffsets {
+    const PROGRAM_ID: Address = SECP256K1_PROGRAM_ID;
+
+    const START: usize = 1;
+
+    const LEN: usize = 11;
+
+    type Pubkey = [u8; 20];
+
+    type Signature = [u8; 65];
+
+    #[inline]
+    fn parse(bytes: &[u8]) -> Self {
+        let field = |i: usize| u16::from_le_bytes([bytes[i], bytes[i + 1]]);
+
+        Secp256k1SignatureOffsets {
+            signature_offset: field(0),
+            signature_instruction_index: bytes[2],
+            eth_address_offset: field(3),
+            eth_address_instruction_index: bytes[5],
+            message_data_offset: field(6),
+            message_data_size: field(8),
+            message_instruction_index: bytes[10],
+        }
+    }
+
+    #[inline]
+    fn resolve<'a>(
+        &self,
+        _current: &'a [u8],
+        source: &impl InstructionDataSource<'a>,
+    ) -> Result<VerifiedSignature<'a, Self>, ProgramError> {
+        let signature = source
+            .instruction_data(self.signature_instruction_index as u16)
+            .and_then(|data| ByteArray::read(data, self.signature_offset as usize));
+        let pubkey = source
+            .instruction_data(self.eth_address_instruction_index as u16)
+            .and_then(|data| ByteArray::read(data, self.eth_address_offset as usize));
+        let message = source
+            .instruction_data(self.message_instruction_index as u16)
+            .and_then(|data| {
+                read_slice(
+                    data,
+                    self.message_data_offset,
+                    self.message_data_size as usize,
+                )
+            });
+
+        match (pubkey, message, signature) {
+            (Some(pubkey), Some(message), Some(signature)) => Ok(VerifiedSignature {
+                pubkey,
+                message,
+                signature,
+            }),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[inline(always)]
+fn read_slice(data: &[u8], offset: u16, len: usize) -> Option<&[u8]> {
+    data.get(offset as usize..)?.get(..len)
+}
+
+/// Iterator over the signatures verified by a precompile instruction.
+pub struct Signatures<'a, O, F> {
+    current: &'a [u8],
+    offsets: ChunksExact<'a, u8>,
+    source: F,
+    marker: PhantomData<O>,
+}
+
+impl<'a, O, F> Signatures<'a, O, F>
+where
+    O: SignatureOffsets,
+    F: InstructionDataSource<'a>,
+{
+    /// Parses the signature count of the precompile instruction `data`.
+    ///
+    /// `source` provides the data of the instructions referenced by the signatures.
+    #[inline]
+    pub fn new(data: &'a [u8], source: F) -> Result<Self, ProgramError> {
+        let count = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+        let offsets = data
+            .get(O::START..O::START + count * O::LEN)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        Ok(Signatures {
+            current: data,
+            offsets: offsets.chunks_exact(O::LEN),
+            source,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, O, F> Iterator for Signatures<'a, O, F>
+where
+    O: SignatureOffsets,
+    F: InstructionDataSource<'a>,
+{
+    type Item = Result<VerifiedSignature<'a, O>, ProgramError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let offsets = O::parse(self.offsets.next()?);
+        Some(offsets.resolve(self.current, &self.source))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'a, O, F> ExactSizeIterator for Signatures<'a, O, F>
+where
+    O: SignatureOffsets,
+    F: InstructionDataSource<'a>,
+{
+}
+
+/// Parses the signatures verified by the Ed25519 precompile instruction `data`.
+#[inline(always)]
+pub fn ed25519_signatures<'a, F>(
+    data: &'a [u8],
+    source: F,
+) -> Result<Signatures<'a, Ed25519SignatureOffsets, F>, ProgramError>
+where
+    F: InstructionDataSource<'a>,
+{
+    Signatures::new(data, source)
+}
+
+/// Parses the signatures verified by the Secp256k1 precompile instruction `data`.
+#[inline(always)]
+pub fn secp256k1_signatures<'a, F>(
+    data: &'a [u8],
+    source: F,
+) -> Result<Signatures<'a, Secp256k1SignatureOffsets, F>, ProgramError>
+where
+    F: InstructionDataSource<'a>,
+{
+    Signatures::new(data, source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"typhoon";
+
+    const ED25519_PUBKEY: [u8; 32] = [
+        0xea, 0x4a, 0x6c, 0x63, 0xe2, 0x9c, 0x52, 0x0a, 0xbe, 0xf5, 0x50, 0x7b, 0x13, 0x2e, 0xc5,
+        0xf9, 0x95, 0x47, 0x76, 0xae, 0xbe, 0xbe, 0x7b, 0x92, 0x42, 0x1e, 0xea, 0x69, 0x14, 0x46,
+        0xd2, 0x2c,
+    ];
+
+    const ED25519_SIGNATURE: [u8; 64] = [
+        0x0f, 0xdf, 0xb0, 0x92, 0x58, 0x60, 0x8d, 0xbb, 0x36, 0xc5, 0x9d, 0xf2, 0xac, 0x54, 0x88,
+        0x88, 0xd5, 0x0b, 0xef, 0x21, 0xb7, 0x20, 0x3d, 0x25, 0xe3, 0xae, 0x10, 0xd6, 0xca, 0xdb,
+        0xb4, 0x4f, 0x53, 0xf9, 0x4a, 0x55, 0x6d, 0xc1, 0x96, 0x2f, 0xc2, 0x88, 0xfb, 0xdb, 0x62,
+        0x83, 0xaf, 0x6f, 0x63, 0xa4, 0x45, 0x94, 0xb9, 0x6a, 0x91, 0x81, 0xff, 0x53, 0x74, 0x21,
+        0x63, 0x0e, 0x0b, 0x0f,
+    ];
+
+    const ETH_ADDRESS: [u8; 20] = [
+        0x4a, 0x62, 0x31, 0x66, 0x23, 0xad, 0x45, 0x7f, 0x02, 0xcd, 0xc5, 0xd9, 0x97, 0xde, 0xd6,
+        0x7a, 0x38, 0x3e, 0xc5, 0x69,
+    ];
+
+    const SECP256K1_SIGNATURE: [u8; 65] = [
+        0x75, 0x80, 0x90, 0xeb, 0xa4, 0x22, 0xa3, 0x80, 0x06, 0x27, 0x2e, 0xe8, 0x5c, 0xd7, 0x78,
+        0x26, 0xe2, 0x2d, 0x29, 0xaa, 0xd3, 0x77, 0x7e, 0xd2, 0xca, 0x14, 0x45, 0x07, 0x01, 0x7d,
+        0x86, 0x29, 0x50, 0x12, 0x01, 0xae, 0x55, 0xef, 0x08, 0x5e, 0xd6, 0x1d, 0xea, 0x83, 0x0a,
+        0x29, 0xb5, 0x8b, 0x4d, 0x3e, 0x50, 0x17, 0xc3, 0xb1, 0x9a, 0x67, 0x58, 0xbf, 0xa7, 0xf2,
+        0x78, 0x9f, 0x45, 0xbe, 0x01,
+    ];
+
+    /// Ed25519 instruction data laid out as the `solana-ed25519-program` client does, with
+    /// the public key, signature and message following the offsets.
+    fn ed25519_instruction() -> [u8; 2 + 14 + 32 + 64 + 7] {
+        let mut data = [0; 2 + 14 + 32 + 64 + 7];
+        data[0] = 1;
+        for (i, field) in [48, u16::MAX, 16, u16::MAX, 112, 7, u16::MAX]
+            .into_iter()
+            .enumerate()
+        {
+            data[2 + 2 * i..4 + 2 * i].copy_from_slice(&field.to_le_bytes());
+        }
+        data[16..48].copy_from_slice(&ED25519_PUBKEY);
+        data[48..112].copy_from_slice(&ED25519_SIGNATURE);
+        data[112..].copy_from_slice(MESSAGE);
+        data
+    }
+
+    /// Secp256k1 instruction data laid out as the `solana-secp256k1-program` client does,
+    /// with the address, signature and message following the offsets, for a precompile
+    /// instruction at index 1.
+    fn secp256k1_instruction() -> [u8; 1 + 11 + 20 + 65 + 7] {
+        let mut data = [0; 1 + 11 + 20 + 65 + 7];
+        data[0] = 1;
+        data[1..3].copy_from_slice(&32u16.to_le_bytes());
+        data[3] = 1;
+        data[4..6].copy_from_slice(&12u16.to_le_bytes());
+        data[6] = 1;
+        data[7..9].copy_from_slice(&97u16.to_le_bytes());
+        data[9..11].copy_from_slice(&7u16.to_le_bytes());
+        data[11] = 1;
+        data[12..32].copy_from_slice(&ETH_ADDRESS);
+        data[32..97].copy_from_slice(&SECP256K1_SIGNATURE);
+        data[97..].copy_from_slice(MESSAGE);
+        data
+    }
+
+    #[test]
+    fn test_ed25519_signatures() {
+        let data = ed25519_instruction();
+        let mut signatures = ed25519_signatures(&data, |_| None).unwrap();
+
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(
+            signatures.next().unwrap().unwrap().into_parts(),
+            (&ED25519_PUBKEY, MESSAGE, &ED25519_SIGNATURE)
+        );
+        assert!(signatures.next().is_none());
+    }
+
+    #[test]
+    fn test_ed25519_offsets() {
+        let data = ed25519_instruction();
+
+        assert_eq!(
+            Ed25519SignatureOffsets::parse(&data[2..16]),
+            Ed25519SignatureOffsets {
+                signature_offset: 48,
+                signature_instruction_index: u16::MAX,
+                public_key_offset: 16,
+                public_key_instruction_index: u16::MAX,
+                message_data_offset: 112,
+                message_data_size: 7,
+                message_instruction_index: u16::MAX,
+            }
+        );
+    }
+
+    #[test]
+    fn test_ed25519_other_instruction() {
+        // The message is read at offset 2 of the instruction at index 3.
+        let mut data = ed25519_instruction();
+        data[10..12].copy_from_slice(&2u16.to_le_bytes());
+        data[14..16].copy_from_slice(&3u16.to_le_bytes());
+        let other = *b"xxtyphoon";
+
+        let signature = ed25519_signatures(&data, |index| (index == 3).then_some(&other[..]))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(signature.message, MESSAGE);
+        assert!(ed25519_signatures(&data, |_| None)
+            .unwrap()
+            .next()
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_ed25519_invalid_data() {
+        let data = ed25519_instruction();
+
+        assert!(ed25519_signatures(&[], |_| None).is_err());
+        // The count exceeds the serialized offsets.
+        assert!(ed25519_signatures(&[3, 0, 0], |_| None).is_err());
+        // The message is out of bounds.
+        let mut truncated = [0; 115];
+        truncated.copy_from_slice(&data[..115]);
+        assert_eq!(
+            ed25519_signatures(&truncated, |_| None)
+                .unwrap()
+                .next()
+                .unwrap(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_signatures() {
+        let data = secp256k1_instruction();
+        let mut signatures =
+            secp256k1_signatures(&data, |index| (index == 1).then_some(&data[..])).unwrap();
+
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(
+            signatures.next().unwrap().unwrap().into_parts(),
+            (&ETH_ADDRESS, MESSAGE, &SECP256K1_SIGNATURE)
+        );
+        assert!(signatures.next().is_none());
+
+        // The offsets always reference an instruction of the transaction.
+        assert!(secp256k1_signatures(&data, |_| None)
+            .unwrap()
+            .next()
+            .unwrap()
+            .is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use {
+    pinocchio::{sysvars::rent::Rent, AccountView, ProgramResult},
+    pinocchio_system::instructions::Transfer,
+};
+
+/// Resize an account and keep it rent-exempt.
+///
+/// The account will be funded by the `payer` if its current lamports
+/// are insufficient for rent-exemption at the new size. Lamports above
+/// the minimum balance are left in the account when it shrinks.
+#[inline(always)]
+pub fn resize_with_minimum_balance(
+    account: &AccountView,
+    new_len: usize,
+    payer: &AccountView,
+    rent_sysvar: &Rent,
+) -> ProgramResult {
+    let required_lamports = rent_sysvar
+        .try_minimum_balance(new_len)?
+        .saturating_sub(account.lamports());
+
+    if required_lamports > 0 {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: required_lamports,
+        }
+        .invoke()?;
+    }
+
+    account.resize(new_len)
+}
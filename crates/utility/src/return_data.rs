@@ -0,0 +1,25 @@
+//! Typed access to the return data of a cross-program invocation.
+
+pub use pinocchio::cpi::ReturnData;
+use {
+    pinocchio::{
+        address::{address_eq, Address},
+        cpi::get_return_data,
+    },
+    typhoon_errors::{Error, ErrorCode},
+};
+
+/// Returns the return data set by `program_id` during the last invocation.
+///
+/// Fails with [`ErrorCode::InvalidReturnData`] when no return data was set or when it was
+/// set by another program, e.g. a program further down the CPI stack.
+#[inline]
+pub fn get_return_data_from(program_id: &Address) -> Result<ReturnData, Error> {
+    let return_data = get_return_data().ok_or(ErrorCode::InvalidReturnData)?;
+
+    if !address_eq(return_data.program_id(), program_id) {
+        return Err(ErrorCode::InvalidReturnData.into());
+    }
+
+    Ok(return_data)
+}
@@ -0,0 +1,184 @@
+use {
+    pinocchio::{
+        address::MAX_SEED_LEN,
+        cpi::{invoke_signed, Signer},
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+        sysvars::rent::Rent,
+        AccountView, Address, ProgramResult,
+    },
+    pinocchio_system::{instructions::Transfer, ID as SYSTEM_PROGRAM_ID},
+};
+
+/// Writes the base address and the length-prefixed seed after the instruction tag,
+/// returning the offset following the seed.
+#[inline(always)]
+fn write_base_and_seed(data: &mut [u8], base: &Address, seed: &str) -> Result<usize, ProgramError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    data[4..36].copy_from_slice(base.as_ref());
+    data[36..44].copy_from_slice(&(seed.len() as u64).to_le_bytes());
+    let offset = 44 + seed.len();
+    data[44..offset].copy_from_slice(seed.as_bytes());
+
+    Ok(offset)
+}
+
+/// Create an account at the address derived from `base` and `seed` with a minimum
+/// balance to be rent-exempt.
+///
+/// The `base` account must sign, its PDA signer seeds can be provided via the
+/// `signers` along with the ones of the `payer`.
+///
+/// Like [`create_account_with_minimum_balance_signed`](crate::create_account_with_minimum_balance_signed),
+/// an account already holding lamports is topped up, allocated and assigned instead.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub fn create_account_with_seed_minimum_balance_signed(
+    account: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    space: usize,
+    owner: &Address,
+    payer: &AccountView,
+    rent_sysvar: &Rent,
+    signers: &[Signer],
+) -> ProgramResult {
+    let lamports = rent_sysvar.try_minimum_balance(space)?;
+
+    if account.lamports() == 0 {
+        let mut data = [0; 124];
+        data[0] = 3;
+        let offset = write_base_and_seed(&mut data, base.address(), seed)?;
+        data[offset..offset + 8].copy_from_slice(&lamports.to_le_bytes());
+        data[offset + 8..offset + 16].copy_from_slice(&(space as u64).to_le_bytes());
+        data[offset + 16..offset + 48].copy_from_slice(owner.as_ref());
+
+        invoke_signed(
+            &InstructionView {
+                program_id: &SYSTEM_PROGRAM_ID,
+                accounts: &[
+                    InstructionAccount::writable_signer(payer.address()),
+                    InstructionAccount::writable(account.address()),
+                    InstructionAccount::readonly_signer(base.address()),
+                ],
+                data: &data[..offset + 48],
+            },
+            &[payer, account, base],
+            signers,
+        )
+    } else {
+        let required_lamports = lamports.saturating_sub(account.lamports());
+
+        if required_lamports > 0 {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: required_lamports,
+            }
+            .invoke_signed(signers)?;
+        }
+
+        allocate_with_seed_signed(account, base, seed, space as u64, owner, signers)?;
+        assign_with_seed_signed(account, base, seed, owner, signers)
+    }
+}
+
+/// Allocate `space` bytes for the account derived from `base` and `seed`.
+#[inline(always)]
+pub fn allocate_with_seed_signed(
+    account: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    space: u64,
+    owner: &Address,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut data = [0; 116];
+    data[0] = 9;
+    let offset = write_base_and_seed(&mut data, base.address(), seed)?;
+    data[offset..offset + 8].copy_from_slice(&space.to_le_bytes());
+    data[offset + 8..offset + 40].copy_from_slice(owner.as_ref());
+
+    invoke_signed(
+        &InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable(account.address()),
+                InstructionAccount::readonly_signer(base.address()),
+            ],
+            data: &data[..offset + 40],
+        },
+        &[account, base],
+        signers,
+    )
+}
+
+/// Assign the account derived from `base` and `seed` to `owner`.
+#[inline(always)]
+pub fn assign_with_seed_signed(
+    account: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    owner: &Address,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut data = [0; 108];
+    data[0] = 10;
+    let offset = write_base_and_seed(&mut data, base.address(), seed)?;
+    data[offset..offset + 32].copy_from_slice(owner.as_ref());
+
+    invoke_signed(
+        &InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable(account.address()),
+                InstructionAccount::readonly_signer(base.address()),
+            ],
+            data: &data[..offset + 32],
+        },
+        &[account, base],
+        signers,
+    )
+}
+
+/// Transfer `lamports` from the account derived from `base`, `seed` and `owner` to `to`.
+#[inline(always)]
+pub fn transfer_with_seed_signed(
+    from: &AccountView,
+    base: &AccountView,
+    seed: &str,
+    owner: &Address,
+    to: &AccountView,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    // The seed is not preceded by the base address, which is read from the accounts.
+    let mut data = [0; 84];
+    data[0] = 11;
+    data[4..12].copy_from_slice(&lamports.to_le_bytes());
+    data[12..20].copy_from_slice(&(seed.len() as u64).to_le_bytes());
+    let offset = 20 + seed.len();
+    data[20..offset].copy_from_slice(seed.as_bytes());
+    data[offset..offset + 32].copy_from_slice(owner.as_ref());
+
+    invoke_signed(
+        &InstructionView {
+            program_id: &SYSTEM_PROGRAM_ID,
+            accounts: &[
+                InstructionAccount::writable(from.address()),
+                InstructionAccount::readonly_signer(base.address()),
+                InstructionAccount::writable(to.address()),
+            ],
+            data: &data[..offset + 32],
+        },
+        &[from, base, to],
+        signers,
+    )
+}
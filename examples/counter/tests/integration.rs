@@ -1,7 +1,13 @@
 use {
-    counter::Counter, litesvm::LiteSVM, solana_address::Address, solana_keypair::Keypair,
-    solana_native_token::LAMPORTS_PER_SOL, solana_signer::Signer, solana_transaction::Transaction,
-    std::path::PathBuf, typhoon_instruction_builder::generate_instructions_client,
+    counter::Counter,
+    litesvm::LiteSVM,
+    solana_address::Address,
+    solana_keypair::Keypair,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    std::path::PathBuf,
+    typhoon_instruction_builder::{generate_instructions_client, ComputeBudget},
 };
 
 const ID: Address = Address::from_str_const("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
@@ -77,3 +83,49 @@ fn integration_test() {
 
     assert!(svm.get_account(&counter_pk).is_none());
 }
+
+#[test]
+fn compute_budget_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+    svm.add_program(ID, &read_program()).unwrap();
+
+    let counter_kp = Keypair::new();
+    let counter_pk = counter_kp.pubkey();
+    let mut ixs = ComputeBudget::default()
+        .unit_limit(20_000)
+        .unit_price(1)
+        .into_instructions();
+    ixs.push(
+        InitializeInstruction {
+            init: InitContext {
+                payer: admin_pk,
+                counter: counter_pk,
+                system: solana_system_interface::program::ID,
+            },
+        }
+        .into_instruction(),
+    );
+    let hash = svm.latest_blockhash();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&admin_pk), &[&admin_kp, &counter_kp], hash);
+    let meta = svm.send_transaction(tx).unwrap();
+    assert!(meta.compute_units_consumed <= 20_000);
+
+    // The increment does not fit in a single compute unit.
+    let mut ixs = ComputeBudget::default().unit_limit(1).into_instructions();
+    ixs.push(
+        IncrementInstruction {
+            ctx: CounterMutContext {
+                counter: counter_pk,
+            },
+        }
+        .into_instruction(),
+    );
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&admin_pk), &[&admin_kp], hash);
+    assert!(svm.send_transaction(tx).is_err());
+}
@@ -32,6 +32,7 @@ CRATES=(
     "cpi-generator"
     "idl-generator"
     "account-macro"
+    "instruction-builder-macro"
     "instruction-builder"
     "cpi-generator-macro"
     "program-id-macro"